@@ -0,0 +1,132 @@
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// Scans `src/<year>/day_XX.rs` and generates the year modules along with the
+// solver registry, so adding a day file is enough to make it runnable. Example
+// inputs next to a day get a generated test when they have a `.answers` file.
+fn main() {
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let src_dir = manifest_dir.join("src");
+    println!("cargo:rerun-if-changed=src");
+
+    let mut generated = String::new();
+    let mut examples = String::new();
+    let mut entries = Vec::new();
+
+    for (year, year_dir) in find_years(&src_dir) {
+        let days = find_days(&year_dir);
+        if days.is_empty() {
+            continue;
+        }
+
+        writeln!(generated, "#[path = {:?}]", year_dir.display().to_string()).unwrap();
+        writeln!(generated, "pub mod y_{} {{", year).unwrap();
+        for day in days.iter() {
+            writeln!(generated, "    pub mod day_{:02};", day).unwrap();
+        }
+        writeln!(generated, "}}").unwrap();
+
+        for (day, name, input) in find_examples(&year_dir, &days) {
+            let answers = input.with_extension("answers");
+            if !answers.is_file() {
+                continue;
+            }
+            writeln!(
+                examples,
+                "example_test!(y{}_{}, {}, {}, {:?}, {:?});",
+                year,
+                name,
+                year,
+                day,
+                input.display().to_string(),
+                answers.display().to_string()
+            )
+            .unwrap();
+        }
+
+        entries.extend(days.into_iter().map(|day| (year, day)));
+    }
+
+    writeln!(generated, "pub static SOLVERS: &[SolverEntry] = &[").unwrap();
+    for (year, day) in entries {
+        writeln!(
+            generated,
+            "    SolverEntry {{ year: {}, day: {}, create: <y_{}::day_{:02}::Solver as crate::solver::Register>::create }},",
+            year, day, year, day
+        )
+        .unwrap();
+    }
+    writeln!(generated, "];").unwrap();
+
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    fs::write(out_dir.join("solvers.rs"), generated).unwrap();
+    fs::write(out_dir.join("examples.rs"), examples).unwrap();
+}
+
+fn find_years(src_dir: &Path) -> Vec<(u32, PathBuf)> {
+    let mut years: Vec<(u32, PathBuf)> = fs::read_dir(src_dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.is_dir())
+        .filter_map(|path| {
+            let name = path.file_name()?.to_str()?;
+            if name.len() != 4 {
+                return None;
+            }
+            let year = name.parse().ok()?;
+            Some((year, path))
+        })
+        .collect();
+    years.sort();
+    years
+}
+
+fn find_days(year_dir: &Path) -> Vec<u32> {
+    let mut days: Vec<u32> = fs::read_dir(year_dir)
+        .unwrap()
+        .filter_map(|entry| {
+            let path = entry.unwrap().path();
+            let name = path.file_name()?.to_str()?;
+            let day = name.strip_prefix("day_")?.strip_suffix(".rs")?;
+            if day.len() != 2 {
+                return None;
+            }
+            day.parse().ok()
+        })
+        .collect();
+    days.sort();
+    days
+}
+
+/// Finds `day_XX_example.txt` and `day_XX_example_N.txt` for the given days,
+/// returning the day, the file stem and the path.
+fn find_examples(year_dir: &Path, days: &[u32]) -> Vec<(u32, String, PathBuf)> {
+    let mut examples: Vec<(u32, String, PathBuf)> = fs::read_dir(year_dir)
+        .unwrap()
+        .filter_map(|entry| {
+            let path = entry.unwrap().path();
+            let name = path
+                .file_name()?
+                .to_str()?
+                .strip_suffix(".txt")?
+                .to_string();
+            let rest = name.strip_prefix("day_")?;
+            let (day, suffix) = rest.split_once("_example")?;
+            if day.len() != 2 {
+                return None;
+            }
+            let day: u32 = day.parse().ok()?;
+            let is_numbered = suffix
+                .strip_prefix('_')
+                .is_some_and(|n| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()));
+            if !days.contains(&day) || !(suffix.is_empty() || is_numbered) {
+                return None;
+            }
+            Some((day, name, path))
+        })
+        .collect();
+    examples.sort();
+    examples
+}
@@ -1,30 +1,28 @@
-use crate::solver::AoCSolver;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+#![allow(clippy::needless_return)]
 
-pub struct Solver {
-    numbers: Vec<u32>,
-}
+use crate::error::{Error, ParseContext, Result};
+use crate::input::numbered_lines;
+use crate::solver::{Answer, Day};
 
-impl Solver {
-    pub fn create() -> Self {
-        Solver {
-            numbers: parse_input(),
-        }
+pub struct Solver;
+
+impl Day for Solver {
+    type Input = Vec<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
     }
-}
 
-impl AoCSolver for Solver {
-    fn solve_part_1(&self) -> String {
-        solve(&self.numbers, 2, 2020).to_string()
+    fn part_1(numbers: &Self::Input) -> Result<Answer> {
+        Ok(solve(numbers, 2, 2020)?.into())
     }
 
-    fn solve_part_2(&self) -> String {
-        solve(&self.numbers, 3, 2020).to_string()
+    fn part_2(numbers: &Self::Input) -> Result<Answer> {
+        Ok(solve(numbers, 3, 2020)?.into())
     }
 }
 
-fn solve(numbers: &Vec<u32>, n: usize, target: u32) -> u32 {
+fn solve(numbers: &[u32], n: usize, target: u32) -> Result<u32> {
     // Initialize a vector of length n with zeros for the values
     let mut indices = vec![0; n];
 
@@ -36,16 +34,13 @@ fn solve(numbers: &Vec<u32>, n: usize, target: u32) -> u32 {
         // Return the product of the numbers if the sum of the numbers matches the target
         let sum: u32 = nums.iter().sum();
         if sum == target {
-            return nums.iter().product();
+            return Ok(nums.iter().product());
         }
 
         // Increment the indices to produce the next unique set
         for i in (0..n).rev() {
-            if indices[i] + 1 == numbers.len() {
-                if i == 0 {
-                    // There are no more indices to increment
-                    break;
-                }
+            // The first index runs past the end once every set has been tried
+            if indices[i] + 1 == numbers.len() && i > 0 {
                 // The index has reached its max value, wrap it back to zero
                 indices[i] = 0;
             } else {
@@ -55,14 +50,29 @@ fn solve(numbers: &Vec<u32>, n: usize, target: u32) -> u32 {
             }
         }
     }
-    panic!("No solution found");
+    Err(Error::NoSolution)
 }
 
-pub fn parse_input() -> Vec<u32> {
-    let file = File::open("src/2020/day_01.txt").unwrap();
-    let reader = BufReader::new(file);
-    return reader
-        .lines()
-        .map(|line| line.unwrap().parse().unwrap())
+pub fn parse_input(input: &str) -> Result<Vec<u32>> {
+    return numbered_lines(input)
+        .map(|(line_number, line)| line.parse().at_line(line_number))
         .collect();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solve_finds_the_product() {
+        let numbers = vec![1721, 979, 366, 299, 675, 1456];
+        assert_eq!(solve(&numbers, 2, 2020).unwrap(), 514579);
+        assert_eq!(solve(&numbers, 3, 2020).unwrap(), 241861950);
+    }
+
+    #[test]
+    fn solve_reports_no_solution() {
+        assert!(matches!(solve(&[1, 2, 3], 2, 2020), Err(Error::NoSolution)));
+        assert!(matches!(solve(&[], 3, 2020), Err(Error::NoSolution)));
+    }
+}
@@ -1,20 +1,22 @@
+#![allow(clippy::needless_return)]
+
 extern crate regex;
 
 use regex::Regex;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
 
-use crate::solver::AoCSolver;
+use crate::error::{Error, ParseContext, Result};
+use crate::input::numbered_lines;
+use crate::solver::{register_solver, Answer, AoCSolver};
 
 pub struct Solver {
     password_db_entries: Vec<(PasswordPolicy, Password)>,
 }
 
 impl Solver {
-    pub fn create() -> Self {
-        Solver {
-            password_db_entries: parse_input(),
-        }
+    pub fn from_input(input: &str) -> Result<Self> {
+        Ok(Solver {
+            password_db_entries: parse_input(input)?,
+        })
     }
 }
 
@@ -26,23 +28,27 @@ pub struct PasswordPolicy {
 
 type Password = String;
 
+register_solver!();
+
 impl AoCSolver for Solver {
-    fn solve_part_1(&self) -> String {
-        self.password_db_entries
+    fn solve_part_1(&self) -> Result<Answer> {
+        Ok(self
+            .password_db_entries
             .iter()
             .filter(|(password_policy, password)| {
                 validate_password_using_count(password_policy, password)
             })
             .count()
-            .to_string()
+            .into())
     }
 
-    fn solve_part_2(&self) -> String {
-        self.password_db_entries
+    fn solve_part_2(&self) -> Result<Answer> {
+        Ok(self
+            .password_db_entries
             .iter()
             .filter(|(policy, password)| validate_password_using_position(policy, password))
             .count()
-            .to_string()
+            .into())
     }
 }
 
@@ -58,25 +64,21 @@ fn validate_password_using_position(policy: &PasswordPolicy, password: &str) ->
         ^ (characters[policy.n2 - 1] == policy.character);
 }
 
-pub fn parse_input() -> Vec<(PasswordPolicy, Password)> {
-    let file = File::open("src/2020/day_02.txt").unwrap();
-    let reader = BufReader::new(file);
-
-    let line_regex = Regex::new(r"(\d+)-(\d+) (\S): (\S+)").unwrap();
-
-    let data = reader
-        .lines()
-        .map(|line| {
-            let line = line.unwrap();
+pub fn parse_input(input: &str) -> Result<Vec<(PasswordPolicy, Password)>> {
+    let line_regex = Regex::new(r"^(\d+)-(\d+) (\S): (\S+)$").unwrap();
 
-            let captures = line_regex.captures(&line).unwrap();
+    let data = numbered_lines(input)
+        .map(|(line_number, line)| {
+            let captures = line_regex.captures(line).ok_or_else(|| {
+                Error::parse(line_number, "expected '<min>-<max> <letter>: <password>'")
+            })?;
 
-            let n1 = captures.get(1).unwrap().as_str().parse::<usize>().unwrap();
-            let n2 = captures.get(2).unwrap().as_str().parse::<usize>().unwrap();
-            let character = captures.get(3).unwrap().as_str().chars().nth(0).unwrap();
-            let password = captures.get(4).unwrap().as_str().to_string();
+            let n1 = captures[1].parse::<usize>().at_line(line_number)?;
+            let n2 = captures[2].parse::<usize>().at_line(line_number)?;
+            let character = captures[3].chars().next().at_line(line_number)?;
+            let password = captures[4].to_string();
             let password_policy = PasswordPolicy { n1, n2, character };
-            return (password_policy, password);
+            return Ok((password_policy, password));
         })
         .collect();
     return data;
@@ -1,9 +1,11 @@
+#![allow(clippy::len_zero, clippy::needless_return)]
+
 use regex::Regex;
 use std::collections::HashMap;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
 
-use crate::solver::AoCSolver;
+use crate::error::{ParseContext, Result};
+use crate::input::numbered_lines;
+use crate::solver::{register_solver, Answer, AoCSolver};
 
 type Passport = HashMap<String, String>;
 
@@ -12,28 +14,32 @@ pub struct Solver {
 }
 
 impl Solver {
-    pub fn create() -> Self {
-        Solver {
-            passports: parse_input(),
-        }
+    pub fn from_input(input: &str) -> Result<Self> {
+        Ok(Solver {
+            passports: parse_input(input)?,
+        })
     }
 }
 
+register_solver!();
+
 impl AoCSolver for Solver {
-    fn solve_part_1(&self) -> String {
-        self.passports
+    fn solve_part_1(&self) -> Result<Answer> {
+        Ok(self
+            .passports
             .iter()
             .filter(|p| validate_passport_fields(p))
             .count()
-            .to_string()
+            .into())
     }
 
-    fn solve_part_2(&self) -> String {
-        self.passports
+    fn solve_part_2(&self) -> Result<Answer> {
+        Ok(self
+            .passports
             .iter()
             .filter(|p| validate_passport_fields_and_values(p))
             .count()
-            .to_string()
+            .into())
     }
 }
 
@@ -109,17 +115,12 @@ fn validate_number(value: &str, min: usize, max: usize) -> bool {
     };
 }
 
-pub fn parse_input() -> Vec<Passport> {
-    let file = File::open("src/2020/day_04.txt").unwrap();
-    let reader = BufReader::new(file);
-
+pub fn parse_input(input: &str) -> Result<Vec<Passport>> {
     let mut passports = Vec::<Passport>::new();
 
     let mut current_passport = Passport::new();
 
-    for line in reader.lines() {
-        let line: &str = &line.unwrap();
-
+    for (line_number, line) in numbered_lines(input) {
         if line.len() == 0 {
             passports.push(current_passport);
             current_passport = Passport::new();
@@ -127,13 +128,12 @@ pub fn parse_input() -> Vec<Passport> {
         }
 
         for kvp in line.split(" ") {
-            let elements: Vec<&str> = kvp.split(":").collect();
-            let (key, value) = (elements[0], elements[1]);
+            let (key, value) = kvp.split_once(":").at_line(line_number)?;
             current_passport.insert(key.to_owned(), value.to_owned());
         }
     }
 
     passports.push(current_passport);
 
-    return passports;
+    return Ok(passports);
 }
@@ -1,32 +1,37 @@
-use crate::solver::AoCSolver;
+#![allow(clippy::needless_return, clippy::redundant_closure)]
+
+use crate::error::{Error, Result};
+use crate::input::numbered_lines;
+use crate::solver::{register_solver, Answer, AoCSolver};
 use std::collections::HashSet;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
 
 pub struct Solver {
     boarding_passes: Vec<String>,
 }
 
 impl Solver {
-    pub fn create() -> Self {
-        Solver {
-            boarding_passes: parse_input(),
-        }
+    pub fn from_input(input: &str) -> Result<Self> {
+        Ok(Solver {
+            boarding_passes: parse_input(input)?,
+        })
     }
 }
 
+register_solver!();
+
 impl AoCSolver for Solver {
-    fn solve_part_1(&self) -> String {
-        self.boarding_passes
+    fn solve_part_1(&self) -> Result<Answer> {
+        Ok(self
+            .boarding_passes
             .iter()
             .map(|x| convert_to_seat_position(x))
             .map(|x| calculate_seat_id(x))
             .max()
-            .unwrap()
-            .to_string()
+            .ok_or(Error::NoSolution)?
+            .into())
     }
 
-    fn solve_part_2(&self) -> String {
+    fn solve_part_2(&self) -> Result<Answer> {
         let filled_seats: HashSet<_> = self
             .boarding_passes
             .iter()
@@ -40,11 +45,11 @@ impl AoCSolver for Solver {
             let curr = filled_seats.contains(&i);
             let next = filled_seats.contains(&(i + 1));
             if prev && !curr && next {
-                return i.to_string();
+                return Ok(i.into());
             }
         }
 
-        panic!("No solution found")
+        Err(Error::NoSolution)
     }
 }
 
@@ -75,8 +80,18 @@ fn partition(text: &str, left_char: char, right_char: char) -> usize {
     return left;
 }
 
-pub fn parse_input() -> Vec<String> {
-    let file = File::open("src/2020/day_05.txt").unwrap();
-    let reader = BufReader::new(file);
-    return reader.lines().map(|line| line.unwrap()).collect();
+pub fn parse_input(input: &str) -> Result<Vec<String>> {
+    return numbered_lines(input)
+        .map(|(line_number, line)| {
+            let (row_text, col_text) = line.split_at(line.len().min(7));
+            let is_valid = row_text.len() == 7
+                && col_text.len() == 3
+                && row_text.chars().all(|c| c == 'F' || c == 'B')
+                && col_text.chars().all(|c| c == 'L' || c == 'R');
+            if !is_valid {
+                return Err(Error::parse(line_number, "expected 7 of F/B then 3 of L/R"));
+            }
+            Ok(line.to_string())
+        })
+        .collect();
 }
@@ -1,9 +1,15 @@
+#![allow(
+    clippy::len_zero,
+    clippy::needless_borrow,
+    clippy::needless_return,
+    clippy::redundant_closure,
+)]
+
 extern crate regex;
 
-use crate::solver::AoCSolver;
+use crate::error::Result;
+use crate::solver::{register_solver, Answer, AoCSolver};
 use std::collections::HashSet;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
 
 type Group = Vec<HashSet<String>>;
 
@@ -12,16 +18,19 @@ pub struct Solver {
 }
 
 impl Solver {
-    pub fn create() -> Self {
-        Solver {
-            data: parse_input(),
-        }
+    pub fn from_input(input: &str) -> Result<Self> {
+        Ok(Solver {
+            data: parse_input(input),
+        })
     }
 }
 
+register_solver!();
+
 impl AoCSolver for Solver {
-    fn solve_part_1(&self) -> String {
-        self.data
+    fn solve_part_1(&self) -> Result<Answer> {
+        Ok(self
+            .data
             .iter()
             .map(|g| {
                 g.iter().fold(HashSet::<String>::new(), |acc, x| {
@@ -30,12 +39,12 @@ impl AoCSolver for Solver {
             }) // fold each group into a single set that contains the union of all sets in the group
             .map(|g| g.len())
             .sum::<usize>()
-            .to_string()
+            .into())
     }
 
-    fn solve_part_2(&self) -> String {
+    fn solve_part_2(&self) -> Result<Answer> {
         let all_letters: HashSet<String> = ('a'..='z').map(|c| String::from(c)).collect();
-        return self
+        return Ok(self
             .data
             .iter()
             .map(|g| {
@@ -45,21 +54,16 @@ impl AoCSolver for Solver {
             }) // fold each group into a single set that contains the intersection of all sets in the group
             .map(|g| g.len())
             .sum::<usize>()
-            .to_string();
+            .into());
     }
 }
 
-pub fn parse_input() -> Vec<Group> {
-    let file = File::open("src/2020/day_06.txt").unwrap();
-    let reader = BufReader::new(file);
-
+pub fn parse_input(input: &str) -> Vec<Group> {
     let mut groups = Vec::<Group>::new();
 
     let mut current_group = Group::new();
 
-    for line in reader.lines() {
-        let line: &str = &line.unwrap();
-
+    for line in input.lines() {
         if line.len() == 0 {
             groups.push(current_group);
             current_group = Vec::new();
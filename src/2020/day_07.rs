@@ -1,8 +1,10 @@
-use crate::solver::AoCSolver;
+#![allow(clippy::needless_borrow, clippy::needless_return, clippy::vec_init_then_push)]
+
+use crate::error::{Error, ParseContext, Result};
+use crate::input::numbered_lines;
+use crate::solver::{register_solver, Answer, AoCSolver};
 use regex::Regex;
 use std::collections::{HashMap, HashSet};
-use std::fs::File;
-use std::io::{BufRead, BufReader};
 
 type BagMap = HashMap<String, Vec<(usize, String)>>;
 
@@ -12,17 +14,19 @@ pub struct Solver {
 }
 
 impl Solver {
-    pub fn create() -> Self {
-        let (parent_map, child_map) = parse_input();
-        Solver {
+    pub fn from_input(input: &str) -> Result<Self> {
+        let (parent_map, child_map) = parse_input(input)?;
+        Ok(Solver {
             parent_map,
             child_map,
-        }
+        })
     }
 }
 
+register_solver!();
+
 impl AoCSolver for Solver {
-    fn solve_part_1(&self) -> String {
+    fn solve_part_1(&self) -> Result<Answer> {
         let mut upstream_bags = HashSet::<String>::new();
         let mut queue = Vec::<String>::new();
         queue.push("shiny gold".to_string());
@@ -36,11 +40,11 @@ impl AoCSolver for Solver {
             }
         }
 
-        return upstream_bags.len().to_string();
+        return Ok(upstream_bags.len().into());
     }
 
-    fn solve_part_2(&self) -> String {
-        return count_children(&"shiny gold".to_string(), &self.child_map).to_string();
+    fn solve_part_2(&self) -> Result<Answer> {
+        return Ok(count_children(&"shiny gold".to_string(), &self.child_map).into());
     }
 }
 
@@ -58,35 +62,30 @@ fn count_children(bag: &String, child_map: &BagMap) -> usize {
     };
 }
 
-pub fn parse_input() -> (BagMap, BagMap) {
-    let file = File::open("src/2020/day_07.txt").unwrap();
-    let reader = BufReader::new(file);
-
+pub fn parse_input(input: &str) -> Result<(BagMap, BagMap)> {
     let parent_bag_regex = Regex::new(r"(.+) bags ").unwrap();
     let child_bags_regex = Regex::new(r"(\d+) ([^.,]+) bag").unwrap();
 
     let mut parent_map = BagMap::new();
     let mut child_map = BagMap::new();
 
-    for line in reader.lines() {
-        let line: &str = &line.unwrap();
-
+    for (line_number, line) in numbered_lines(input) {
         let parent = parent_bag_regex
             .captures(line)
-            .unwrap()
+            .ok_or_else(|| Error::parse(line_number, "expected '<color> bags contain ...'"))?
             .get(1)
             .unwrap()
             .as_str();
 
         for cap in child_bags_regex.captures_iter(line) {
-            let qty: usize = cap.get(1).unwrap().as_str().parse().unwrap();
+            let qty: usize = cap[1].parse().at_line(line_number)?;
             let child = cap.get(2).unwrap().as_str();
 
             insert(child.to_owned(), qty, parent.to_owned(), &mut parent_map);
             insert(parent.to_owned(), qty, child.to_owned(), &mut child_map);
         }
     }
-    return (parent_map, child_map);
+    return Ok((parent_map, child_map));
 }
 
 fn insert(key: String, qty: usize, value: String, nodes: &mut BagMap) {
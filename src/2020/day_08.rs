@@ -1,44 +1,48 @@
-use crate::solver::AoCSolver;
+#![allow(clippy::needless_return)]
+
+use crate::error::{Error, ParseContext, Result};
+use crate::input::numbered_lines;
+use crate::solver::{register_solver, Answer, AoCSolver};
 use std;
 use std::collections::HashSet;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
 
 pub struct Solver {
     instructions: Vec<(String, isize)>,
 }
 
 impl Solver {
-    pub fn create() -> Self {
-        Solver {
-            instructions: parse_input(),
-        }
+    pub fn from_input(input: &str) -> Result<Self> {
+        Ok(Solver {
+            instructions: parse_input(input)?,
+        })
     }
 }
 
+register_solver!();
+
 impl AoCSolver for Solver {
-    fn solve_part_1(&self) -> String {
+    fn solve_part_1(&self) -> Result<Answer> {
         let mut visited_lines = HashSet::<usize>::new();
         let mut line = 0;
         let mut acc = 0;
         loop {
-            let (operation, argument) = &self.instructions[line];
+            let (operation, argument) = self.instructions.get(line).ok_or(Error::NoSolution)?;
             perform_operation(operation, *argument, &mut line, &mut acc);
             if visited_lines.contains(&line) {
                 // We have hit a loop.
-                return acc.to_string();
+                return Ok(acc.into());
             }
             visited_lines.insert(line);
         }
     }
 
-    fn solve_part_2(&self) -> String {
+    fn solve_part_2(&self) -> Result<Answer> {
         let mut visited_lines = HashSet::<usize>::new();
         let mut line = 0;
         let mut acc = 0;
         let mut swapped_line = 0;
         loop {
-            let (operation, argument) = &self.instructions[line];
+            let (operation, argument) = self.instructions.get(line).ok_or(Error::NoSolution)?;
             let mut operation: &str = operation;
 
             if line == swapped_line {
@@ -63,7 +67,7 @@ impl AoCSolver for Solver {
                 continue;
             } else if line == self.instructions.len() {
                 // The last line has executed meaning that the correct line was swapped.
-                return acc.to_string();
+                return Ok(acc.into());
             }
 
             visited_lines.insert(line);
@@ -89,16 +93,12 @@ fn perform_operation(operation: &str, argument: isize, line: &mut usize, acc: &m
     };
 }
 
-pub fn parse_input() -> Vec<(String, isize)> {
-    let file = File::open("src/2020/day_08.txt").unwrap();
-    let reader = BufReader::new(file);
-    return reader
-        .lines()
-        .map(|line| {
-            let line = line.unwrap();
-            let operation = line[0..3].to_owned();
-            let argument: isize = line[4..].parse().unwrap();
-            (operation, argument)
+pub fn parse_input(input: &str) -> Result<Vec<(String, isize)>> {
+    return numbered_lines(input)
+        .map(|(line_number, line)| {
+            let (operation, argument) = line.split_once(' ').at_line(line_number)?;
+            let argument: isize = argument.parse().at_line(line_number)?;
+            Ok((operation.to_owned(), argument))
         })
         .collect();
 }
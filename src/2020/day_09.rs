@@ -1,28 +1,32 @@
-use crate::solver::AoCSolver;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+#![allow(clippy::needless_borrow, clippy::needless_return, clippy::ptr_arg)]
+
+use crate::error::{Error, ParseContext, Result};
+use crate::input::numbered_lines;
+use crate::solver::{register_solver, Answer, AoCSolver};
 
 pub struct Solver {
     data: Vec<u64>,
 }
 
 impl Solver {
-    pub fn create() -> Self {
-        Solver {
-            data: parse_input(),
-        }
+    pub fn from_input(input: &str) -> Result<Self> {
+        Ok(Solver {
+            data: parse_input(input)?,
+        })
     }
 }
 
+register_solver!();
+
 impl AoCSolver for Solver {
-    fn solve_part_1(&self) -> String {
+    fn solve_part_1(&self) -> Result<Answer> {
         let preamble = 25;
-        return find_invalid_number(&self.data, preamble).to_string();
+        return Ok(find_invalid_number(&self.data, preamble)?.into());
     }
 
-    fn solve_part_2(&self) -> String {
+    fn solve_part_2(&self) -> Result<Answer> {
         let preamble = 25;
-        let target_number = find_invalid_number(&self.data, preamble);
+        let target_number = find_invalid_number(&self.data, preamble)?;
         let mut longest_chain = Vec::<u64>::new();
         for i in 0..self.data.len() {
             let mut sum = 0;
@@ -46,18 +50,19 @@ impl AoCSolver for Solver {
                 }
             }
         }
-        return (longest_chain.iter().min().unwrap() + longest_chain.iter().max().unwrap())
-            .to_string();
+        let min = longest_chain.iter().min().ok_or(Error::NoSolution)?;
+        let max = longest_chain.iter().max().ok_or(Error::NoSolution)?;
+        return Ok((min + max).into());
     }
 }
 
-fn find_invalid_number(values: &Vec<u64>, preamble: usize) -> u64 {
+fn find_invalid_number(values: &Vec<u64>, preamble: usize) -> Result<u64> {
     for i in preamble..values.len() {
         if !validate(&values, i, preamble) {
-            return values[i];
+            return Ok(values[i]);
         }
     }
-    return 0;
+    return Err(Error::NoSolution);
 }
 
 fn validate(values: &Vec<u64>, index: usize, preamble: usize) -> bool {
@@ -75,12 +80,9 @@ fn validate(values: &Vec<u64>, index: usize, preamble: usize) -> bool {
     return false;
 }
 
-pub fn parse_input() -> Vec<u64> {
-    let file = File::open("src/2020/day_09.txt").unwrap();
-    let reader = BufReader::new(file);
-    let values = reader
-        .lines()
-        .map(|x| x.unwrap().parse().unwrap())
+pub fn parse_input(input: &str) -> Result<Vec<u64>> {
+    let values = numbered_lines(input)
+        .map(|(line_number, x)| x.parse().at_line(line_number))
         .collect();
     return values;
 }
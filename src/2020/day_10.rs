@@ -1,28 +1,32 @@
-use crate::solver::AoCSolver;
+#![allow(clippy::needless_return, clippy::ptr_arg)]
+
+use crate::error::{ParseContext, Result};
+use crate::input::numbered_lines;
+use crate::solver::{register_solver, Answer, AoCSolver};
 use std::collections::BTreeMap;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
 
 pub struct Solver {
     data: Vec<u64>,
 }
 
 impl Solver {
-    pub fn create() -> Self {
-        Solver {
-            data: parse_input(),
-        }
+    pub fn from_input(input: &str) -> Result<Self> {
+        Ok(Solver {
+            data: parse_input(input)?,
+        })
     }
 }
 
+register_solver!();
+
 impl AoCSolver for Solver {
-    fn solve_part_1(&self) -> String {
+    fn solve_part_1(&self) -> Result<Answer> {
         let counts = get_counts(self.data.windows(2).map(|w| w[1] - w[0]));
-        return (counts[&1u64] * counts[&3u64]).to_string();
+        return Ok((counts[&1u64] * counts[&3u64]).into());
     }
 
-    fn solve_part_2(&self) -> String {
-        return count_paths(&self.data).to_string();
+    fn solve_part_2(&self) -> Result<Answer> {
+        return Ok(count_paths(&self.data).into());
     }
 }
 
@@ -67,13 +71,10 @@ fn sort_and_cap(values: &mut Vec<u64>) {
     values.push(adapter_value); // Add a value representing the adapter jolts
 }
 
-pub fn parse_input() -> Vec<u64> {
-    let file = File::open("src/2020/day_10.txt").unwrap();
-    let reader = BufReader::new(file);
-    let mut values = reader
-        .lines()
-        .map(|x| x.unwrap().parse().unwrap())
-        .collect();
+pub fn parse_input(input: &str) -> Result<Vec<u64>> {
+    let mut values = numbered_lines(input)
+        .map(|(line_number, x)| x.parse().at_line(line_number))
+        .collect::<Result<_>>()?;
     sort_and_cap(&mut values);
-    return values;
+    return Ok(values);
 }
@@ -1,213 +1,131 @@
-use crate::solver::AoCSolver;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+#![allow(clippy::needless_return)]
 
-type Grid = Vec<Vec<char>>;
+use crate::error::Result;
+use crate::solver::{register_solver, Answer, AoCSolver};
+use crate::util::automaton::{line_of_sight, Dense, Rule};
+use crate::util::grid::Grid;
+use crate::util::point_2d::Point2D;
 
 pub struct Solver {
-    grid: Grid,
+    grid: Grid<char>,
 }
 
 impl Solver {
-    pub fn create() -> Self {
-        Solver {
-            grid: parse_input(),
-        }
+    pub fn from_input(input: &str) -> Result<Self> {
+        Ok(Solver {
+            grid: parse_input(input)?,
+        })
     }
 }
 
+register_solver!();
+
 impl AoCSolver for Solver {
-    fn solve_part_1(&self) -> String {
-        let mut grid = self.grid.clone();
-        simulate_until_stable(&mut grid, 4, 1);
-        return count_occupied_seats(&grid).to_string();
+    fn solve_part_1(&self) -> Result<Answer> {
+        return Ok(simulate_until_stable(&self.grid, 4, 1).into());
     }
 
-    fn solve_part_2(&self) -> String {
-        let mut grid = self.grid.clone();
-        simulate_until_stable(&mut grid, 5, usize::MAX);
-        return count_occupied_seats(&grid).to_string();
+    fn solve_part_2(&self) -> Result<Answer> {
+        return Ok(simulate_until_stable(&self.grid, 5, usize::MAX).into());
     }
 }
 
-// fn print(grid: &Grid) {
-//     for row in grid {
-//         let s: String = row.into_iter().clone().collect();
-//         println!("{}", s);
-//     }
-//     println!("");
-// }
-
-fn simulate_until_stable(grid: &mut Grid, min_seats_to_vacate: usize, sight_distance: usize) {
-    while simulate(grid, min_seats_to_vacate, sight_distance) {}
+/// Fills and empties the seats until nothing changes, returning how many of
+/// them end up occupied. An empty seat gets taken when no seat in sight is
+/// occupied, and an occupied one is left when enough of them are.
+fn simulate_until_stable(
+    grid: &Grid<char>,
+    min_seats_to_vacate: usize,
+    sight_distance: usize,
+) -> usize {
+    let seats = Dense::new(
+        grid,
+        |&cell| cell != '.',
+        |point| visible_seats(grid, point, sight_distance),
+    );
+    let rule = Rule::new([0], 0..min_seats_to_vacate);
+
+    let occupied = seats.state(|point| grid[point] == '#');
+    let occupied = seats.run_until_stable(occupied, rule);
+    return occupied.iter().filter(|&&seat| seat).count();
 }
 
-fn simulate(grid: &mut Grid, min_seats_to_vacate: usize, sight_distance: usize) -> bool {
-    let starting_grid = grid.clone();
-
-    let mut changed = false;
-
-    for y in 0..grid.len() {
-        for x in 0..grid[0].len() {
-            match starting_grid[y][x] {
-                'L' => {
-                    if !occupied_seats_in_sight_at_least(&starting_grid, x, y, 1, sight_distance) {
-                        changed = true;
-                        grid[y][x] = '#'
-                    }
-                }
-                '#' => {
-                    if occupied_seats_in_sight_at_least(
-                        &starting_grid,
-                        x,
-                        y,
-                        min_seats_to_vacate,
-                        sight_distance,
-                    ) {
-                        changed = true;
-                        grid[y][x] = 'L'
-                    }
-                }
-                _ => {}
-            };
-        }
-    }
-
-    return changed;
+fn visible_seats(
+    grid: &Grid<char>,
+    point: Point2D,
+    sight_distance: usize,
+) -> impl Iterator<Item = Point2D> + '_ {
+    return line_of_sight(grid, point, sight_distance, |&cell| cell != '.');
 }
 
-fn occupied_seats_in_sight_at_least(
-    grid: &Grid,
-    x: usize,
-    y: usize,
-    min_occupied: usize,
-    max_distance: usize,
-) -> bool {
-    let mut occupied_count: usize = 0;
-    let directions: [(isize, isize); 8] = [
-        (1, 0),
-        (1, 1),
-        (0, 1),
-        (-1, 1),
-        (-1, 0),
-        (-1, -1),
-        (0, -1),
-        (1, -1),
-    ];
-
-    for (x_dir, y_dir) in directions.iter() {
-        if check_in_direction(grid, x, y, *x_dir, *y_dir, max_distance) {
-            occupied_count += 1;
-            if occupied_count == min_occupied {
-                return true;
-            }
-        }
-    }
-
-    return false;
-}
-
-fn check_in_direction(
-    grid: &Grid,
-    x_start: usize,
-    y_start: usize,
-    x_step: isize,
-    y_step: isize,
-    max_distance: usize,
-) -> bool {
-    let mut x = x_start as isize;
-    let mut y = y_start as isize;
-    let mut distance = 0;
-    loop {
-        x += x_step;
-        y += y_step;
-        distance += 1;
-
-        // Check if the coordinates are out of bounds.
-        if x < 0 || x >= grid[0].len() as isize || y < 0 || y >= grid.len() as isize {
-            return false;
-        }
-
-        // Check to see if we found a seat.
-        match grid[y as usize][x as usize] {
-            '#' => return true,
-            'L' => return false,
-            _ => {}
-        };
-
-        if distance == max_distance {
-            return false;
-        }
-    }
-}
-
-fn count_occupied_seats(grid: &Grid) -> usize {
-    return grid.iter().flatten().filter(|&x| x == &'#').count();
-}
-
-pub fn parse_input() -> Grid {
-    let file = File::open("src/2020/day_11.txt").unwrap();
-    let reader = BufReader::new(file);
-    let grid = reader
-        .lines()
-        .map(|line| line.unwrap().chars().collect())
-        .collect();
-    return grid;
+pub fn parse_input(input: &str) -> Result<Grid<char>> {
+    return Grid::parse(input, Some);
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn occupied_seats_in_sight(grid: &Grid<char>, point: Point2D, sight_distance: usize) -> usize {
+        let seats = visible_seats(grid, point, sight_distance);
+        seats.filter(|seat| grid[*seat] == '#').count()
+    }
+
     #[test]
     fn occupied_seats_none_found() {
-        let grid = vec![
-            vec!['.', '#', '#', '.', '#', '#', '.'],
-            vec!['#', '.', '#', '.', '#', '.', '#'],
-            vec!['#', '#', '.', '.', '.', '#', '#'],
-            vec!['.', '.', '.', 'L', '.', '.', '.'],
-            vec!['#', '#', '.', '.', '.', '#', '#'],
-            vec!['#', '.', '#', '.', '#', '.', '#'],
-            vec!['.', '#', '#', '.', '#', '#', '.'],
-        ];
+        let grid = parse_input(
+            "\
+             .##.##.\n\
+             #.#.#.#\n\
+             ##...##\n\
+             ...L...\n\
+             ##...##\n\
+             #.#.#.#\n\
+             .##.##.",
+        )
+        .unwrap();
         assert_eq!(
-            occupied_seats_in_sight_at_least(&grid, 3, 3, 1, usize::MAX),
-            false
+            occupied_seats_in_sight(&grid, Point2D::new(3, 3), usize::MAX),
+            0
         );
     }
 
     #[test]
     fn occupied_seats_all_found() {
-        let grid = vec![
-            vec!['.', '.', '.', '.', '.', '.', '.', '#', '.'],
-            vec!['.', '.', '.', '#', '.', '.', '.', '.', '.'],
-            vec!['.', '#', '.', '.', '.', '.', '.', '.', '.'],
-            vec!['.', '.', '.', '.', '.', '.', '.', '.', '.'],
-            vec!['.', '.', '#', 'L', '.', '.', '.', '.', '#'],
-            vec!['.', '.', '.', '.', '#', '.', '.', '.', '.'],
-            vec!['.', '.', '.', '.', '.', '.', '.', '.', '.'],
-            vec!['#', '.', '.', '.', '.', '.', '.', '.', '.'],
-            vec!['.', '.', '.', '#', '.', '.', '.', '.', '.'],
-        ];
+        let grid = parse_input(
+            "\
+             .......#.\n\
+             ...#.....\n\
+             .#.......\n\
+             .........\n\
+             ..#L....#\n\
+             ....#....\n\
+             .........\n\
+             #........\n\
+             ...#.....",
+        )
+        .unwrap();
         assert_eq!(
-            occupied_seats_in_sight_at_least(&grid, 3, 4, 8, usize::MAX),
-            true
+            occupied_seats_in_sight(&grid, Point2D::new(3, 4), usize::MAX),
+            8
         );
     }
 
     #[test]
     fn occupied_seats_dist_capped() {
-        let grid = vec![
-            vec!['.', '.', '.', '.', '.', '.', '.', '#', '.'],
-            vec!['.', '.', '.', '#', '.', '.', '.', '.', '.'],
-            vec!['.', '#', '.', '.', '.', '.', '.', '.', '.'],
-            vec!['.', '.', '.', '.', '.', '.', '.', '.', '.'],
-            vec!['.', '.', '#', 'L', '.', '.', '.', '.', '#'],
-            vec!['.', '.', '.', '.', '#', '.', '.', '.', '.'],
-            vec!['.', '.', '.', '.', '.', '.', '.', '.', '.'],
-            vec!['#', '.', '.', '.', '.', '.', '.', '.', '.'],
-            vec!['.', '.', '.', '#', '.', '.', '.', '.', '.'],
-        ];
-        assert_eq!(occupied_seats_in_sight_at_least(&grid, 3, 4, 3, 2), true);
+        let grid = parse_input(
+            "\
+             .......#.\n\
+             ...#.....\n\
+             .#.......\n\
+             .........\n\
+             ..#L....#\n\
+             ....#....\n\
+             .........\n\
+             #........\n\
+             ...#.....",
+        )
+        .unwrap();
+        assert_eq!(occupied_seats_in_sight(&grid, Point2D::new(3, 4), 2), 3);
     }
 }
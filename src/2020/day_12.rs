@@ -1,12 +1,16 @@
-use crate::solver::AoCSolver;
-use std::collections::HashMap;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+#![allow(clippy::needless_return)]
+
+use crate::error::{Error, ParseContext, Result};
+use crate::input::numbered_lines;
+use crate::solver::{register_solver, Answer, AoCSolver};
+use crate::util::direction::Direction4;
+use crate::util::point_2d::Point2D;
 
 enum Instruction {
-    Move(i64, i64),
-    MoveForward(i64),
-    Rotate(i64),
+    Move(Direction4, isize),
+    MoveForward(isize),
+    /// Quarter turns, clockwise when positive.
+    Turn(isize),
 }
 
 pub struct Solver {
@@ -14,105 +18,75 @@ pub struct Solver {
 }
 
 impl Solver {
-    pub fn create() -> Self {
-        Solver {
-            instructions: parse_input(),
-        }
+    pub fn from_input(input: &str) -> Result<Self> {
+        Ok(Solver {
+            instructions: parse_input(input)?,
+        })
     }
 }
 
-impl AoCSolver for Solver {
-    fn solve_part_1(&self) -> String {
-        let mut map = HashMap::<i64, (i64, i64)>::new();
-        map.insert(0, (1, 0));
-        map.insert(90, (0, 1));
-        map.insert(180, (-1, 0));
-        map.insert(270, (0, -1));
+register_solver!();
 
-        let mut x = 0;
-        let mut y = 0;
-        let mut heading = 0;
+impl AoCSolver for Solver {
+    fn solve_part_1(&self) -> Result<Answer> {
+        let mut position = Point2D::ORIGIN;
+        let mut heading = Direction4::Right;
 
         for instruction in &self.instructions {
             match instruction {
-                Instruction::Move(dx, dy) => {
-                    x += dx;
-                    y += dy;
-                }
-                Instruction::MoveForward(amount) => {
-                    let (x_dir, y_dir) = map[&heading];
-                    x += amount * x_dir;
-                    y += amount * y_dir;
-                }
-                Instruction::Rotate(amount) => {
-                    heading = (((heading + amount) % 360) + 360) % 360;
-                }
+                Instruction::Move(direction, amount) => position += direction.offset() * *amount,
+                Instruction::MoveForward(amount) => position += heading.offset() * *amount,
+                Instruction::Turn(quarter_turns) => heading = heading.turn(*quarter_turns),
             };
-            // println!("({}, {})", x, y);
+            // println!("{}", position);
         }
-        return calc_manhattan_dist(x, y).to_string();
+        return Ok(position.manhattan_distance(Point2D::ORIGIN).into());
     }
 
-    fn solve_part_2(&self) -> String {
-        let (mut x, mut y) = (0, 0);
-        let (mut w_x, mut w_y) = (10, 1);
+    fn solve_part_2(&self) -> Result<Answer> {
+        let mut position = Point2D::ORIGIN;
+        let mut waypoint = Point2D::new(10, -1);
 
         for instruction in &self.instructions {
             match instruction {
-                Instruction::Move(dx, dy) => {
-                    w_x += dx;
-                    w_y += dy;
-                }
-                Instruction::MoveForward(amount) => {
-                    x += amount * w_x;
-                    y += amount * w_y;
-                }
-                Instruction::Rotate(amount) => {
-                    let (n_x, n_y) = rotate_point(w_x, w_y, *amount as f64, 0, 0);
-                    w_x = n_x;
-                    w_y = n_y;
+                Instruction::Move(direction, amount) => waypoint += direction.offset() * *amount,
+                Instruction::MoveForward(amount) => position += waypoint * *amount,
+                Instruction::Turn(quarter_turns) => {
+                    for _ in 0..quarter_turns.rem_euclid(4) {
+                        waypoint = waypoint.rotate_right();
+                    }
                 }
             };
-            // println!("Ship ({}, {}) | Waypoint ({}, {})", x, y, w_x, w_y);
+            // println!("Ship {} | Waypoint {}", position, waypoint);
         }
-        return calc_manhattan_dist(x, y).to_string();
+        return Ok(position.manhattan_distance(Point2D::ORIGIN).into());
     }
 }
 
-fn rotate_point(px: i64, py: i64, theta: f64, cx: i64, cy: i64) -> (i64, i64) {
-    let theta = theta.to_radians();
-    return (
-        theta.cos() as i64 * (px - cx) - theta.sin() as i64 * (py - cy) + cx,
-        theta.sin() as i64 * (px - cx) + theta.cos() as i64 * (py - cy) + cy,
-    );
-}
-
-fn calc_manhattan_dist(x: i64, y: i64) -> i64 {
-    x.abs() + y.abs()
-}
-
-fn parse_input() -> Vec<Instruction> {
-    let file = File::open("src/2020/day_12.txt").unwrap();
-    let reader = BufReader::new(file);
+fn parse_input(input: &str) -> Result<Vec<Instruction>> {
     let mut instructions = Vec::<Instruction>::new();
-    let mut map = HashMap::<&str, (i64, i64)>::new();
-    map.insert("N", (0, 1));
-    map.insert("S", (0, -1));
-    map.insert("E", (1, 0));
-    map.insert("W", (-1, 0));
 
-    for line in reader.lines() {
-        let line = line.unwrap();
-        let (letter, value) = line.split_at(1);
-        let value = value.parse::<i64>().unwrap();
+    for (line_number, line) in numbered_lines(input) {
+        let letter = line.get(..1).at_line(line_number)?;
+        let value = line[1..].parse::<isize>().at_line(line_number)?;
 
         let instruction = match letter {
-            "L" => Instruction::Rotate(value),
-            "R" => Instruction::Rotate(-value), // Inverting the rotation value for right handed turns encodes the direction into the value
+            "L" | "R" if value % 90 != 0 => {
+                return Err(Error::parse(
+                    line_number,
+                    "expected a multiple of 90 degrees",
+                ));
+            }
+            "L" => Instruction::Turn(-value / 90),
+            "R" => Instruction::Turn(value / 90),
             "F" => Instruction::MoveForward(value),
-            _ => Instruction::Move(value * map[letter].0, value * map[letter].1),
+            "N" | "E" | "S" | "W" => Instruction::Move(Direction4::parse(letter).unwrap(), value),
+            _ => {
+                let message = format!("unknown action '{}'", letter);
+                return Err(Error::parse(line_number, message));
+            }
         };
         instructions.push(instruction);
     }
-    return instructions;
+    return Ok(instructions);
 }
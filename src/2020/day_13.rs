@@ -1,6 +1,7 @@
-use crate::solver::AoCSolver;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+#![allow(clippy::needless_return)]
+
+use crate::error::{Error, ParseContext, Result};
+use crate::solver::{register_solver, Answer, AoCSolver};
 
 pub struct Solver {
     departure_time: i64,
@@ -8,28 +9,30 @@ pub struct Solver {
 }
 
 impl Solver {
-    pub fn create() -> Self {
-        let (departure_time, bus_lines) = parse_input();
-        Solver {
+    pub fn from_input(input: &str) -> Result<Self> {
+        let (departure_time, bus_lines) = parse_input(input)?;
+        Ok(Solver {
             departure_time,
             bus_lines,
-        }
+        })
     }
 }
 
+register_solver!();
+
 impl AoCSolver for Solver {
-    fn solve_part_1(&self) -> String {
+    fn solve_part_1(&self) -> Result<Answer> {
         for time in self.departure_time.. {
             for (_, bus_line) in self.bus_lines.iter() {
                 if time % bus_line == 0 {
-                    return ((time - self.departure_time) * bus_line).to_string();
+                    return Ok(((time - self.departure_time) * bus_line).into());
                 }
             }
         }
-        panic!("No solution found")
+        Err(Error::NoSolution)
     }
 
-    fn solve_part_2(&self) -> String {
+    fn solve_part_2(&self) -> Result<Answer> {
         // Use Chinese Remainder Theorem
         let prod: i64 = self.bus_lines.iter().map(|(_, b)| b).product();
         let result = self
@@ -39,7 +42,7 @@ impl AoCSolver for Solver {
             .sum::<i64>()
             .rem_euclid(prod);
 
-        return result.to_string();
+        return Ok(result.into());
     }
 }
 
@@ -48,23 +51,43 @@ fn inv_mod(x: i64, p: i64) -> i64 {
     (0..p - 2).fold(1, |o, _| (o * x) % p)
 }
 
-fn parse_input() -> (i64, Vec<(i64, i64)>) {
-    let file = File::open("src/2020/day_13.txt").unwrap();
-    let mut reader = BufReader::new(file);
+fn parse_input(input: &str) -> Result<(i64, Vec<(i64, i64)>)> {
+    let mut lines = input.lines();
 
-    let mut departure_time = String::new();
-    reader.read_line(&mut departure_time).unwrap();
-    let departure_time: i64 = departure_time.trim_end().parse().unwrap();
+    let departure_time: i64 = lines.next().at_line(1)?.parse().at_line(1)?;
 
-    let mut bus_lines = String::new();
-    reader.read_line(&mut bus_lines).unwrap();
-    let bus_lines: Vec<(i64, i64)> = bus_lines
-        .trim_end()
+    let bus_lines: Vec<(i64, i64)> = lines
+        .next()
+        .at_line(2)?
         .split(",")
         .enumerate()
         .filter(|&(_, x)| x != "x")
-        .map(|(i, x)| (i as i64, x.parse().unwrap()))
-        .collect();
+        .map(|(i, x)| Ok((i as i64, x.parse().at_line(2)?)))
+        .collect::<Result<_>>()?;
+    if bus_lines.is_empty() {
+        return Err(Error::parse(2, "expected at least one bus"));
+    }
+    if bus_lines.iter().any(|&(_, bus_line)| bus_line <= 0) {
+        return Err(Error::parse(2, "bus ids must be positive"));
+    }
 
-    return (departure_time, bus_lines);
+    return Ok((departure_time, bus_lines));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_input_rejects_missing_or_zero_buses() {
+        assert!(parse_input("939\n7,13,x,x,59").is_ok());
+        assert!(matches!(
+            parse_input("939\nx,x"),
+            Err(Error::Parse { line: 2, .. })
+        ));
+        assert!(matches!(
+            parse_input("939\n7,0"),
+            Err(Error::Parse { line: 2, .. })
+        ));
+    }
 }
@@ -1,12 +1,14 @@
+#![allow(clippy::map_flatten, clippy::needless_borrow, clippy::needless_return)]
+
 extern crate regex;
 
-use crate::solver::AoCSolver;
+use crate::error::{Error, ParseContext, Result};
+use crate::input::numbered_lines;
+use crate::solver::{register_solver, Answer, AoCSolver};
 use bit_field::BitField;
 use itertools::Itertools;
 use regex::Regex;
 use std::collections::HashMap;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
 
 enum Instruction {
     SetMask(String),
@@ -18,15 +20,17 @@ pub struct Solver {
 }
 
 impl Solver {
-    pub fn create() -> Self {
-        Solver {
-            instructions: parse_input(),
-        }
+    pub fn from_input(input: &str) -> Result<Self> {
+        Ok(Solver {
+            instructions: parse_input(input)?,
+        })
     }
 }
 
+register_solver!();
+
 impl AoCSolver for Solver {
-    fn solve_part_1(&self) -> String {
+    fn solve_part_1(&self) -> Result<Answer> {
         let mut mask = &String::new();
         let mut memory = HashMap::<u64, u64>::new();
 
@@ -50,10 +54,10 @@ impl AoCSolver for Solver {
                 }
             };
         }
-        return memory.values().sum::<u64>().to_string();
+        return Ok(memory.values().sum::<u64>().into());
     }
 
-    fn solve_part_2(&self) -> String {
+    fn solve_part_2(&self) -> Result<Answer> {
         let mut mask = &String::new();
         let mut memory = HashMap::<u64, u64>::new();
 
@@ -96,31 +100,32 @@ impl AoCSolver for Solver {
                 }
             };
         }
-        return memory.values().sum::<u64>().to_string();
+        return Ok(memory.values().sum::<u64>().into());
     }
 }
 
-fn parse_input() -> Vec<Instruction> {
-    let file = File::open("src/2020/day_14.txt").unwrap();
-    let reader = BufReader::new(file);
+fn parse_input(input: &str) -> Result<Vec<Instruction>> {
     let mut instructions = Vec::<Instruction>::new();
 
-    let mem_regex = Regex::new(r"\[(\d+)\] = (\d+)").unwrap();
-    let mask_regex = Regex::new(r"mask = (.+)").unwrap();
+    let mem_regex = Regex::new(r"^mem\[(\d+)\] = (\d+)$").unwrap();
+    let mask_regex = Regex::new(r"^mask = ([01X]{36})$").unwrap();
 
-    for line in reader.lines() {
-        let line = line.unwrap();
+    for (line_number, line) in numbered_lines(input) {
         let instruction = if line.starts_with("mem") {
-            let caps = mem_regex.captures(&line).unwrap();
-            let address = caps.get(1).unwrap().as_str().parse::<u64>().unwrap();
-            let value = caps.get(2).unwrap().as_str().parse::<u64>().unwrap();
+            let caps = mem_regex
+                .captures(line)
+                .ok_or_else(|| Error::parse(line_number, "expected 'mem[<address>] = <value>'"))?;
+            let address = caps[1].parse::<u64>().at_line(line_number)?;
+            let value = caps[2].parse::<u64>().at_line(line_number)?;
             Instruction::SetValue(address, value)
         } else {
-            let caps = mask_regex.captures(&line).unwrap();
-            let mask = caps.get(1).unwrap().as_str().to_owned();
+            let caps = mask_regex
+                .captures(line)
+                .ok_or_else(|| Error::parse(line_number, "expected 'mask = <36 of 0/1/X>'"))?;
+            let mask = caps[1].to_owned();
             Instruction::SetMask(mask)
         };
         instructions.push(instruction);
     }
-    return instructions;
+    return Ok(instructions);
 }
@@ -1,4 +1,7 @@
-use crate::solver::AoCSolver;
+#![allow(clippy::needless_return, clippy::unwrap_or_default)]
+
+use crate::error::{ParseContext, Result};
+use crate::solver::{register_solver, Answer, AoCSolver};
 use std::collections::HashMap;
 
 pub struct Solver {
@@ -6,20 +9,26 @@ pub struct Solver {
 }
 
 impl Solver {
-    pub fn create() -> Self {
-        Solver {
-            numbers: vec![10, 16, 6, 0, 1, 17],
-        }
+    pub fn from_input(input: &str) -> Result<Self> {
+        Ok(Solver {
+            numbers: input
+                .trim()
+                .split(',')
+                .map(|number| number.parse().at_line(1))
+                .collect::<Result<_>>()?,
+        })
     }
 }
 
+register_solver!();
+
 impl AoCSolver for Solver {
-    fn solve_part_1(&self) -> String {
-        return get_number_at_turn(2020, &self.numbers).to_string();
+    fn solve_part_1(&self) -> Result<Answer> {
+        return Ok(get_number_at_turn(2020, &self.numbers).into());
     }
 
-    fn solve_part_2(&self) -> String {
-        return get_number_at_turn(30000000, &self.numbers).to_string();
+    fn solve_part_2(&self) -> Result<Answer> {
+        return Ok(get_number_at_turn(30000000, &self.numbers).into());
     }
 }
 
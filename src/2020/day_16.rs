@@ -1,7 +1,14 @@
-use crate::solver::AoCSolver;
+#![allow(
+    clippy::needless_return,
+    clippy::ptr_arg,
+    clippy::unnecessary_cast,
+    clippy::unwrap_or_default,
+)]
+
+use crate::error::{ParseContext, Result};
+use crate::input::numbered_lines;
+use crate::solver::{register_solver, AoCSolver, Answer};
 use std::collections::HashMap;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
 use std::ops::Range;
 
 pub struct Solver {
@@ -11,13 +18,13 @@ pub struct Solver {
 }
 
 impl Solver {
-    pub fn create() -> Self {
-        let file_data = parse_file();
-        return Solver {
+    pub fn from_input(input: &str) -> Result<Self> {
+        let file_data = parse_file(input)?;
+        return Ok(Solver {
             fields: file_data.fields,
             our_ticket: file_data.our_ticket,
             nearby_tickets: file_data.nearby_tickets,
-        };
+        });
     }
 
     fn validate_ticket(&self, ticket: &Vec<u32>) -> bool {
@@ -34,18 +41,20 @@ impl Solver {
     }
 }
 
+register_solver!();
+
 impl AoCSolver for Solver {
-    fn solve_part_1(&self) -> String {
+    fn solve_part_1(&self) -> Result<Answer> {
         let ticket_scanning_error_rate = self.
             nearby_tickets
             .iter()
             .flatten()
             .filter(|&v| !self.validate_value(*v))
             .sum::<u32>();
-        return ticket_scanning_error_rate.to_string();
+        return Ok(ticket_scanning_error_rate.into());
     }
 
-    fn solve_part_2(&self) -> String {
+    fn solve_part_2(&self) -> Result<Answer> {
         // Filter out invalid nearby tickets
         let valid_tickets: Vec<&Vec<u32>> = self.
             nearby_tickets
@@ -92,7 +101,7 @@ impl AoCSolver for Solver {
             .map(|i| self.our_ticket[i as usize] as u64)
             .product();
 
-        return result.to_string();
+        return Ok(result.into());
     }
 }
 
@@ -119,10 +128,7 @@ struct FileData {
     nearby_tickets: Vec<Vec<u32>>,
 }
 
-fn parse_file() -> FileData {
-    let file = File::open("src/2020/day_16.txt").unwrap();
-    let reader = BufReader::new(file);
-
+fn parse_file(input: &str) -> Result<FileData> {
     let mut fields = Vec::<Field>::new();
     let mut our_ticket = Vec::<u32>::new();
     let mut nearby_tickets = Vec::<Vec<u32>>::new();
@@ -131,15 +137,13 @@ fn parse_file() -> FileData {
     let mut read_our_ticket = false;
     let mut read_nearby_tickets = false;
 
-    for line in reader.lines() {
-        let line = line.unwrap();
-
+    for (line_number, line) in numbered_lines(input) {
         if read_fields {
             if line.trim() == "" {
                 read_fields = false;
                 continue;
             }
-            let field = read_field(&line);
+            let field = read_field(line).at_line(line_number)?;
             fields.push(field);
         }
         if line == "your ticket:" {
@@ -151,14 +155,14 @@ fn parse_file() -> FileData {
                 read_our_ticket = false;
                 continue;
             }
-            our_ticket = read_ticket(&line);
+            our_ticket = read_ticket(line).at_line(line_number)?;
         }
         if line == "nearby tickets:" {
             read_nearby_tickets = true;
             continue;
         }
         if read_nearby_tickets {
-            nearby_tickets.push(read_ticket(&line))
+            nearby_tickets.push(read_ticket(line).at_line(line_number)?)
         }
     }
 
@@ -167,25 +171,31 @@ fn parse_file() -> FileData {
         our_ticket,
         nearby_tickets,
     };
-    return file_data;
+    return Ok(file_data);
 }
 
-fn read_field(line: &str) -> Field {
-    let sections: Vec<&str> = line.split(":").collect();
-    let name = sections[0].to_owned();
-    let line = sections[1];
+fn read_field(line: &str) -> Result<Field, String> {
+    let (name, line) = line
+        .split_once(":")
+        .ok_or("expected '<name>: <ranges>'")?;
+    let name = name.to_owned();
     let mut ranges = Vec::<Range<u32>>::new();
     for text in line.split("or") {
-        let sections: Vec<&str> = text.trim().split("-").collect();
-        let min: u32 = sections[0].parse().unwrap();
-        let max: u32 = sections[1].parse().unwrap();
+        let (min, max) = text
+            .trim()
+            .split_once("-")
+            .ok_or("expected '<min>-<max>'")?;
+        let min: u32 = min.parse().map_err(|_| format!("invalid number '{}'", min))?;
+        let max: u32 = max.parse().map_err(|_| format!("invalid number '{}'", max))?;
         // One is added here because the range specified in the file is inclusive
         // on the upper end while the range data type is not.
         ranges.push(min..max + 1);
     }
-    Field { name, ranges }
+    Ok(Field { name, ranges })
 }
 
-fn read_ticket(line: &str) -> Vec<u32> {
-    line.split(",").map(|x| x.parse().unwrap()).collect()
+fn read_ticket(line: &str) -> Result<Vec<u32>, String> {
+    line.split(",")
+        .map(|x| x.parse().map_err(|_| format!("invalid number '{}'", x)))
+        .collect()
 }
@@ -1,133 +1,68 @@
-use crate::solver::AoCSolver;
-use itertools::Itertools;
-use std::io::{BufRead, BufReader};
-use std::{cmp::max, cmp::min};
-use std::{collections::HashSet, fs::File};
+#![allow(clippy::needless_return)]
 
-type Point = Vec<i32>;
+use crate::error::Result;
+use crate::solver::{register_solver, Answer, AoCSolver};
+use crate::util::automaton::{step_sparse, Moore, Rule};
+use std::collections::HashSet;
+
+type Point = [i32; 2];
 
 pub struct Solver {
     points: HashSet<Point>,
 }
 
 impl Solver {
-    pub fn create() -> Self {
-        Solver {
-            points: parse_file(),
-        }
+    pub fn from_input(input: &str) -> Result<Self> {
+        Ok(Solver {
+            points: parse_file(input),
+        })
     }
 }
 
+register_solver!();
+
 impl AoCSolver for Solver {
-    fn solve_part_1(&self) -> String {
-        let points = self
-            .points
-            .iter()
-            .map(|point| vec![point[0], point[1], 0])
-            .collect();
-        solve(&points, 3).to_string()
+    fn solve_part_1(&self) -> Result<Answer> {
+        Ok(solve::<3>(&self.points).into())
     }
 
-    fn solve_part_2(&self) -> String {
-        let points = self
-            .points
-            .iter()
-            .map(|point| vec![point[0], point[1], 0, 0])
-            .collect();
-        solve(&points, 4).to_string()
+    fn solve_part_2(&self) -> Result<Answer> {
+        Ok(solve::<4>(&self.points).into())
     }
 }
 
-fn solve(points: &HashSet<Point>, dimensions: u32) -> usize {
-    let mut points = points.clone();
-    for _cycle in 0..6 {
-        let mut new_points = HashSet::<Point>::new();
-        let bounds = get_bounds(&points, dimensions);
-
-        for point in bounds
-            .iter()
-            .map(|b| (b.min - 1)..=(b.max + 1))
-            .multi_cartesian_product()
-        {
-            let count = count_active_neighbors(&point, &points);
-            if count == 3 || (count == 2 && points.contains(&point)) {
-                new_points.insert(point);
-            }
-        }
+/// Runs six cycles in `N` dimensions, starting from the slice of the input.
+fn solve<const N: usize>(points: &HashSet<Point>) -> usize {
+    let neighbourhood = Moore::<N>::new();
+    let rule = Rule::new([3], [2, 3]);
+    let mut points: HashSet<[i32; N]> = points
+        .iter()
+        .map(|point| {
+            let mut cube = [0; N];
+            cube[..2].copy_from_slice(point);
+            cube
+        })
+        .collect();
 
-        points = new_points;
+    for _cycle in 0..6 {
+        points = step_sparse(&points, |cube| neighbourhood.neighbours(cube), rule);
         // println!("Cycle {} has {} active points", _cycle, points.len());
     }
 
     return points.len();
 }
 
-fn get_bounds(points: &HashSet<Point>, dimensions: u32) -> Vec<Range> {
-    let mut bounds = vec![Range::empty(); dimensions as usize];
-    for point in points {
-        for d in 0..dimensions as usize {
-            bounds[d] = expand_range(bounds.get(d).unwrap(), point[d]);
-        }
-    }
-    return bounds;
-}
-
-fn expand_range(range: &Range, value: i32) -> Range {
-    Range {
-        min: min(range.min, value),
-        max: max(range.max, value),
-    }
-}
-
-fn count_active_neighbors(point: &Point, points: &HashSet<Point>) -> u32 {
-    let mut active_neightbors = 0;
-    for neighbor in point
-        .iter()
-        .map(|d| (d - 1)..=(d + 1))
-        .multi_cartesian_product()
-    {
-        if &neighbor == point {
-            // println!("{:?} = {:?}", neighbor, point);
-            continue;
-        }
-        if points.contains(&neighbor) {
-            // println!("{:?} contains {:?}", points, neighbor);
-            active_neightbors += 1;
-        }
-    }
-    return active_neightbors;
-}
-
-#[derive(Clone)]
-struct Range {
-    min: i32,
-    max: i32,
-}
-
-impl Range {
-    fn empty() -> Range {
-        Range {
-            min: i32::MAX,
-            max: i32::MIN,
-        }
-    }
-}
-
-fn parse_file() -> HashSet<Point> {
-    let file = File::open("src/2020/day_17.txt").unwrap();
-    let reader = BufReader::new(file);
-
+fn parse_file(input: &str) -> HashSet<Point> {
     let mut data = HashSet::<Point>::new();
 
-    for (y, line) in reader.lines().enumerate() {
-        let line = line.unwrap();
+    for (y, line) in input.lines().enumerate() {
         for x in line
             .chars()
             .enumerate()
             .filter(|&(_, c)| c == '#')
             .map(|(x, _)| x)
         {
-            data.insert(vec![x as i32, y as i32]);
+            data.insert([x as i32, y as i32]);
         }
     }
     return data;
@@ -1,4 +1,7 @@
-use crate::solver::AoCSolver;
+#![allow(clippy::needless_return)]
+
+use crate::error::{ParseContext, Result};
+use crate::solver::{register_solver, Answer, AoCSolver};
 use nom::{
     branch::alt,
     bytes::complete::tag,
@@ -9,8 +12,6 @@ use nom::{
     sequence::{delimited, pair},
     IResult,
 };
-use std::fs::File;
-use std::io::{BufRead, BufReader};
 use std::str::FromStr;
 
 // nom code from https://github.com/Geal/nom/blob/master/tests/arithmetic.rs
@@ -21,33 +22,33 @@ pub struct Solver {
 }
 
 impl Solver {
-    pub fn create() -> Self {
-        let file = File::open("src/2020/day_18.txt").unwrap();
-        let reader = BufReader::new(file);
-        return Solver {
-            lines: reader.lines().map(|l| l.unwrap()).collect(),
-        };
+    pub fn from_input(input: &str) -> Result<Self> {
+        return Ok(Solver {
+            lines: input.lines().map(String::from).collect(),
+        });
     }
 }
 
+register_solver!();
+
 impl AoCSolver for Solver {
-    fn solve_part_1(&self) -> String {
-        self.lines
-            .iter()
-            .map(|l| p1_expr(l.as_str()).unwrap().1)
-            .sum::<i64>()
-            .to_string()
+    fn solve_part_1(&self) -> Result<Answer> {
+        Ok(evaluate_lines(&self.lines, p1_expr)?.into())
     }
 
-    fn solve_part_2(&self) -> String {
-        self.lines
-            .iter()
-            .map(|l| p2_expr(l.as_str()).unwrap().1)
-            .sum::<i64>()
-            .to_string()
+    fn solve_part_2(&self) -> Result<Answer> {
+        Ok(evaluate_lines(&self.lines, p2_expr)?.into())
     }
 }
 
+fn evaluate_lines(lines: &[String], expr: fn(&str) -> IResult<&str, i64>) -> Result<i64> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(index, line)| Ok(expr(line.as_str()).at_line(index + 1)?.1))
+        .sum()
+}
+
 fn p1_expr(i: &str) -> IResult<&str, i64> {
     let (i, init) = p1_factor(i)?;
 
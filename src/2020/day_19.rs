@@ -1,10 +1,12 @@
+#![allow(clippy::comparison_to_empty, clippy::needless_return)]
+
 extern crate regex;
 
-use crate::solver::AoCSolver;
+use crate::error::{Error, ParseContext, Result};
+use crate::input::numbered_lines;
+use crate::solver::{register_solver, Answer, AoCSolver};
 use regex::Regex;
 use std::collections::HashMap;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
 
 pub struct Solver {
     rules: HashMap<u8, String>,
@@ -12,27 +14,29 @@ pub struct Solver {
 }
 
 impl Solver {
-    pub fn create() -> Self {
-        let (rules, messages) = parse_input();
-        return Solver { rules, messages };
+    pub fn from_input(input: &str) -> Result<Self> {
+        let (rules, messages) = parse_input(input)?;
+        return Ok(Solver { rules, messages });
     }
 }
 
+register_solver!();
+
 impl AoCSolver for Solver {
-    fn solve_part_1(&self) -> String {
+    fn solve_part_1(&self) -> Result<Answer> {
         let mut expanded_rules = HashMap::<u8, String>::new();
         expand_rule(0, 0, &self.rules, &mut expanded_rules);
 
         let rule = Regex::new(&format!("^{}$", &expanded_rules[&0])).unwrap();
-        return self
+        return Ok(self
             .messages
             .iter()
             .filter(|m| rule.is_match(m))
             .count()
-            .to_string();
+            .into());
     }
 
-    fn solve_part_2(&self) -> String {
+    fn solve_part_2(&self) -> Result<Answer> {
         // Replace rules, introducing recursive rules
         let mut rules = self.rules.clone();
         rules.insert(8, "42 | 42 8".to_owned());
@@ -43,39 +47,54 @@ impl AoCSolver for Solver {
         expand_rule(0, 0, &rules, &mut expanded_rules);
 
         let rule = Regex::new(&format!("^{}$", &expanded_rules[&0])).unwrap();
-        return self
+        return Ok(self
             .messages
             .iter()
             .filter(|m| rule.is_match(m))
             .count()
-            .to_string();
+            .into());
     }
 }
 
-fn parse_input() -> (HashMap<u8, String>, Vec<String>) {
-    let file = File::open("src/2020/day_19.txt").unwrap();
-    let reader = BufReader::new(file);
-
+fn parse_input(input: &str) -> Result<(HashMap<u8, String>, Vec<String>)> {
     let mut rules = HashMap::<u8, String>::new();
     let mut messages = Vec::<String>::new();
+    let mut referenced_rules = Vec::<(usize, u8)>::new();
 
     let mut is_rule_line = true;
-    for line in reader.lines() {
-        let line = line.unwrap();
+    for (line_number, line) in numbered_lines(input) {
         if line == "" {
             is_rule_line = false;
             continue;
         }
         if is_rule_line {
-            let index = line.find(":").unwrap();
-            let num: u8 = line[..index].parse().unwrap();
-            rules.insert(num, line[index + 1..].trim().to_owned());
+            let index = line.find(":").at_line(line_number)?;
+            let num: u8 = line[..index].parse().at_line(line_number)?;
+            let rule = line[index + 1..].trim();
+            if !rule.starts_with('"') {
+                for component in rule.split(' ').filter(|&c| c != "|") {
+                    referenced_rules.push((line_number, component.parse().at_line(line_number)?));
+                }
+            }
+            rules.insert(num, rule.to_owned());
         } else {
             messages.push(line.to_owned());
         }
     }
 
-    return (rules, messages);
+    if !rules.contains_key(&0) {
+        return Err(Error::parse(1, "rule 0 is missing"));
+    }
+    for (line_number, num) in referenced_rules {
+        if !rules.contains_key(&num) {
+            return Err(Error::parse(
+                line_number,
+                format!("rule {} is missing", num),
+            ));
+        }
+    }
+
+    return Ok((rules, messages));
 }
 
 fn expand_rule(
@@ -95,7 +114,7 @@ fn expand_rule(
         let is_option = rule.contains('|');
         let mut expanded_rule = String::new();
         if is_option {
-            expanded_rule.push('(');
+            expanded_rule.push_str("(?:");
         }
         for component in rule.split(' ') {
             if component == "|" {
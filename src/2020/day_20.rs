@@ -1,27 +1,35 @@
-use crate::solver::AoCSolver;
-use std::{
-    collections::VecDeque,
-    io::{BufRead, BufReader},
-};
-use std::{
-    collections::{HashMap, HashSet},
-    fs::File,
-};
+#![allow(
+    clippy::comparison_to_empty,
+    clippy::into_iter_on_ref,
+    clippy::iter_cloned_collect,
+    clippy::needless_range_loop,
+    clippy::needless_return,
+    clippy::ptr_arg,
+    clippy::useless_vec,
+)]
+
+use crate::error::{ParseContext, Result};
+use crate::input::numbered_lines;
+use crate::solver::{register_solver, Answer, AoCSolver};
+use std::collections::VecDeque;
+use std::collections::{HashMap, HashSet};
 
 pub struct Solver {
     tiles: Vec<Tile>,
 }
 
 impl Solver {
-    pub fn create() -> Self {
-        Solver {
-            tiles: parse_file(),
-        }
+    pub fn from_input(input: &str) -> Result<Self> {
+        Ok(Solver {
+            tiles: parse_file(input)?,
+        })
     }
 }
 
+register_solver!();
+
 impl AoCSolver for Solver {
-    fn solve_part_1(&self) -> String {
+    fn solve_part_1(&self) -> Result<Answer> {
         let mut tiles = self.tiles.clone();
         let tiles_grid = match_tiles(&mut tiles);
 
@@ -55,14 +63,14 @@ impl AoCSolver for Solver {
         let last_row = tiles_grid.last().unwrap();
 
         // Return the product of the four corners.
-        return (first_row.first().unwrap().number
+        return Ok((first_row.first().unwrap().number
             * first_row.last().unwrap().number
             * last_row.first().unwrap().number
             * last_row.last().unwrap().number)
-            .to_string();
+            .into());
     }
 
-    fn solve_part_2(&self) -> String {
+    fn solve_part_2(&self) -> Result<Answer> {
         let mut tiles = self.tiles.clone();
         let mut tiles_grid = match_tiles(&mut tiles);
 
@@ -92,7 +100,7 @@ impl AoCSolver for Solver {
         // println!("Found {} sea monsters", count);
         // println!("Water Roughness: {}", water_roughness);
 
-        return water_roughness.to_string();
+        return Ok(water_roughness.into());
     }
 }
 
@@ -400,30 +408,29 @@ fn detect_sea_monsters(tile: &Tile) -> (u32, usize) {
     (sea_monster_count, water_roughness)
 }
 
-fn parse_file() -> Vec<Tile> {
-    let file = File::open("src/2020/day_20.txt").unwrap();
-    let reader = BufReader::new(file);
-
+fn parse_file(input: &str) -> Result<Vec<Tile>> {
     let mut tiles = Vec::<Tile>::new();
 
-    for line in reader.lines() {
-        let line = line.unwrap();
-
+    for (line_number, line) in numbered_lines(input) {
         if line.starts_with("Tile") {
+            let number = line
+                .strip_prefix("Tile ")
+                .and_then(|line| line.strip_suffix(":"))
+                .at_line(line_number)?;
             let tile = Tile {
-                number: line[5..=8].parse().unwrap(),
+                number: number.parse().at_line(line_number)?,
                 data: Vec::<Vec<bool>>::new(),
             };
             tiles.push(tile);
         } else if line == "" {
             continue;
         } else {
-            let tile = tiles.last_mut().unwrap();
+            let tile = tiles.last_mut().at_line(line_number)?;
             tile.data.push(line.chars().map(|c| c == '#').collect());
         }
     }
 
-    return tiles;
+    return Ok(tiles);
 }
 
 #[cfg(test)]
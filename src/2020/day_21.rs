@@ -1,4 +1,8 @@
-use crate::solver::AoCSolver;
+#![allow(clippy::needless_return, clippy::ptr_arg)]
+
+use crate::error::{ParseContext, Result};
+use crate::input::numbered_lines;
+use crate::solver::{register_solver, Answer, AoCSolver};
 use nom::{
     branch::alt,
     bytes::complete::tag,
@@ -9,8 +13,6 @@ use nom::{
 };
 use std::{
     collections::{HashMap, HashSet, VecDeque},
-    fs::File,
-    io::{BufRead, BufReader},
     iter::FromIterator,
 };
 
@@ -19,15 +21,17 @@ pub struct Solver {
 }
 
 impl Solver {
-    pub fn create() -> Self {
-        Solver {
-            foods: parse_input(),
-        }
+    pub fn from_input(input: &str) -> Result<Self> {
+        Ok(Solver {
+            foods: parse_input(input)?,
+        })
     }
 }
 
+register_solver!();
+
 impl AoCSolver for Solver {
-    fn solve_part_1(&self) -> String {
+    fn solve_part_1(&self) -> Result<Answer> {
         let allergen_ingredients = find_allergen_ingredients(&self.foods);
         let allergen_ingredients: HashSet<String> = allergen_ingredients
             .into_iter()
@@ -39,17 +43,17 @@ impl AoCSolver for Solver {
             .flat_map(|f| &f.ingredients)
             .filter(|i| !allergen_ingredients.contains(*i))
             .count();
-        return safe_count.to_string();
+        return Ok(safe_count.into());
     }
 
-    fn solve_part_2(&self) -> String {
+    fn solve_part_2(&self) -> Result<Answer> {
         let mut allergen_ingredients = find_allergen_ingredients(&self.foods);
         allergen_ingredients.sort_by_key(|(allergen, _)| allergen.to_owned());
         let canonical_dangerous_ingredients: Vec<String> = allergen_ingredients
             .iter()
             .map(|(_, ingredient)| ingredient.to_owned())
             .collect();
-        return canonical_dangerous_ingredients.join(",");
+        return Ok(canonical_dangerous_ingredients.join(",").into());
     }
 }
 
@@ -116,12 +120,9 @@ struct Food {
     allergens: HashSet<String>,
 }
 
-fn parse_input() -> Vec<Food> {
-    let file = File::open("src/2020/day_21.txt").unwrap();
-    let reader = BufReader::new(file);
-    reader
-        .lines()
-        .map(|line| parse_food(&line.unwrap()).unwrap().1)
+fn parse_input(input: &str) -> Result<Vec<Food>> {
+    numbered_lines(input)
+        .map(|(line_number, line)| Ok(parse_food(line).at_line(line_number)?.1))
         .collect()
 }
 
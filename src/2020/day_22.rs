@@ -1,9 +1,14 @@
-use crate::solver::AoCSolver;
-use std::{
-    collections::{HashSet, VecDeque},
-    fs::File,
-    io::{BufRead, BufReader},
-};
+#![allow(
+    clippy::comparison_to_empty,
+    clippy::filter_next,
+    clippy::needless_return,
+    clippy::ptr_arg,
+)]
+
+use crate::error::{ParseContext, Result};
+use crate::input::numbered_lines;
+use crate::solver::{register_solver, Answer, AoCSolver};
+use std::collections::{HashSet, VecDeque};
 
 type Deck = VecDeque<u32>;
 
@@ -12,15 +17,17 @@ pub struct Solver {
 }
 
 impl Solver {
-    pub fn create() -> Self {
-        Solver {
-            decks: parse_input(),
-        }
+    pub fn from_input(input: &str) -> Result<Self> {
+        Ok(Solver {
+            decks: parse_input(input)?,
+        })
     }
 }
 
+register_solver!();
+
 impl AoCSolver for Solver {
-    fn solve_part_1(&self) -> String {
+    fn solve_part_1(&self) -> Result<Answer> {
         let mut decks = self.decks.clone();
 
         while decks.iter().all(|deck| !deck.is_empty()) {
@@ -39,13 +46,13 @@ impl AoCSolver for Solver {
         let winner = decks.iter().filter(|deck| !deck.is_empty()).next().unwrap();
 
         // Score winning deck
-        return score_deck(&winner).to_string();
+        return Ok(score_deck(winner).into());
     }
 
-    fn solve_part_2(&self) -> String {
+    fn solve_part_2(&self) -> Result<Answer> {
         let mut decks = self.decks.clone();
         let winner = play_recursive_combat(&mut decks);
-        return score_deck(&decks[winner]).to_string();
+        return Ok(score_deck(&decks[winner]).into());
     }
 }
 
@@ -112,22 +119,18 @@ fn score_deck(deck: &Deck) -> u32 {
         .sum()
 }
 
-fn parse_input() -> Vec<Deck> {
-    let file = File::open("src/2020/day_22.txt").unwrap();
-    let reader = BufReader::new(file);
-
+fn parse_input(input: &str) -> Result<Vec<Deck>> {
     let mut decks = Vec::<Deck>::new();
 
-    for line in reader.lines() {
-        let line = line.unwrap();
-
+    for (line_number, line) in numbered_lines(input) {
         if line == "" {
             continue;
         } else if line.starts_with("Player") {
             decks.push(Deck::new());
         } else {
-            decks.last_mut().unwrap().push_back(line.parse().unwrap());
+            let card = line.parse().at_line(line_number)?;
+            decks.last_mut().at_line(line_number)?.push_back(card);
         }
     }
-    return decks;
+    return Ok(decks);
 }
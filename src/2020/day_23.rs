@@ -1,32 +1,47 @@
-use crate::solver::AoCSolver;
+#![allow(
+    clippy::needless_range_loop,
+    clippy::needless_return,
+    clippy::ptr_arg,
+    clippy::slow_vector_initialization,
+)]
+
+use crate::error::{ParseContext, Result};
+use crate::solver::{register_solver, Answer, AoCSolver};
 
 pub struct Solver {
     cups: Vec<usize>,
 }
 
 impl Solver {
-    pub fn create() -> Self {
-        Solver {
-            cups: vec![2, 8, 4, 5, 7, 3, 9, 6, 1],
-        }
+    pub fn from_input(input: &str) -> Result<Self> {
+        Ok(Solver {
+            cups: input
+                .trim()
+                .chars()
+                .map(|cup| Ok(cup.to_digit(10).at_line(1)? as usize))
+                .collect::<Result<_>>()?,
+        })
     }
 }
 
+register_solver!();
+
 impl AoCSolver for Solver {
-    fn solve_part_1(&self) -> String {
+    fn solve_part_1(&self) -> Result<Answer> {
         let mut linked_list = convert_to_linked_list(&self.cups);
         move_cups(100, self.cups[0], &mut linked_list);
         let cups = convert_from_linked_list(&linked_list);
 
-        return cups
+        return Ok(cups
             .iter()
             .skip(1)
             .map(|c| c.to_string())
             .collect::<Vec<String>>()
-            .concat();
+            .concat()
+            .into());
     }
 
-    fn solve_part_2(&self) -> String {
+    fn solve_part_2(&self) -> Result<Answer> {
         let mut cups = self.cups.clone();
         // Add remaining cups up through one million
         let max = *cups.iter().max().unwrap();
@@ -36,7 +51,7 @@ impl AoCSolver for Solver {
         move_cups(10_000_000, cups[0], &mut linked_list);
         let cups = convert_from_linked_list(&linked_list);
 
-        return (cups[1] * cups[2]).to_string();
+        return Ok((cups[1] * cups[2]).into());
     }
 }
 
@@ -1,26 +1,34 @@
-use crate::solver::AoCSolver;
+#![allow(clippy::needless_return)]
+
+use crate::error::{ParseContext, Result};
+use crate::input::numbered_lines;
+use crate::solver::{register_solver, Answer, AoCSolver};
 
 pub struct Solver {
     public_keys: Vec<u64>,
 }
 
 impl Solver {
-    pub fn create() -> Self {
-        Solver {
-            public_keys: vec![9093927, 11001876],
-        }
+    pub fn from_input(input: &str) -> Result<Self> {
+        Ok(Solver {
+            public_keys: numbered_lines(input)
+                .map(|(line_number, line)| line.parse().at_line(line_number))
+                .collect::<Result<_>>()?,
+        })
     }
 }
 
+register_solver!();
+
 impl AoCSolver for Solver {
-    fn solve_part_1(&self) -> String {
+    fn solve_part_1(&self) -> Result<Answer> {
         let loop_size = find_loop_size(self.public_keys[0]);
         let encryption_key = loop_transform(self.public_keys[1], loop_size);
-        return encryption_key.to_string();
+        return Ok(encryption_key.into());
     }
 
-    fn solve_part_2(&self) -> String {
-        return 0.to_string();
+    fn solve_part_2(&self) -> Result<Answer> {
+        return Ok(0.into());
     }
 }
 
@@ -1,31 +1,36 @@
-use crate::solver::AoCSolver;
+#![allow(clippy::needless_return)]
+
+use crate::error::{ParseContext, Result};
+use crate::input::numbered_lines;
+use crate::solver::{register_solver, Answer, AoCSolver};
 use itertools::Itertools;
-use std::{fs::File, io::BufRead, io::BufReader};
 
 pub struct Solver {
     input: Vec<u32>,
 }
 
 impl Solver {
-    pub fn create() -> Self {
-        Solver {
-            input: parse_input(),
-        }
+    pub fn from_input(input: &str) -> Result<Self> {
+        Ok(Solver {
+            input: parse_input(input)?,
+        })
     }
 }
 
+register_solver!();
+
 impl AoCSolver for Solver {
-    fn solve_part_1(&self) -> String {
+    fn solve_part_1(&self) -> Result<Answer> {
         let count = self
             .input
             .iter()
             .tuple_windows()
             .filter(|(a, b)| b > a)
             .count();
-        return count.to_string();
+        return Ok(count.into());
     }
 
-    fn solve_part_2(&self) -> String {
+    fn solve_part_2(&self) -> Result<Answer> {
         let count = self
             .input
             .iter()
@@ -34,15 +39,12 @@ impl AoCSolver for Solver {
             .tuple_windows()
             .filter(|(a, b)| b > a)
             .count();
-        return count.to_string();
+        return Ok(count.into());
     }
 }
 
-pub fn parse_input() -> Vec<u32> {
-    let file = File::open("src/2021/day_01.txt").unwrap();
-    let reader = BufReader::new(file);
-    return reader
-        .lines()
-        .map(|line| line.unwrap().parse().unwrap())
+pub fn parse_input(input: &str) -> Result<Vec<u32>> {
+    return numbered_lines(input)
+        .map(|(line_number, line)| line.parse().at_line(line_number))
         .collect();
 }
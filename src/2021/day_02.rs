@@ -1,76 +1,90 @@
-use crate::solver::AoCSolver;
-use std::{fs::File, io::BufRead, io::BufReader};
+#![allow(clippy::needless_return)]
+
+use crate::error::{Error, ParseContext, Result};
+use crate::input::numbered_lines;
+use crate::solver::{register_solver, Answer, AoCSolver};
+use crate::util::direction::Direction4;
+use crate::util::point_2d::Point2D;
 
 pub struct Solver {
     instructions: Vec<Instruction>,
 }
 
 impl Solver {
-    pub fn create() -> Self {
-        Solver {
-            instructions: parse_input(),
-        }
+    pub fn from_input(input: &str) -> Result<Self> {
+        Ok(Solver {
+            instructions: parse_input(input)?,
+        })
     }
 }
 
-pub enum Instruction {
-    Up(i64),
-    Down(i64),
-    Forward(i64),
+pub struct Instruction {
+    command: Command,
+    amount: isize,
 }
 
+#[derive(Clone, Copy)]
+pub enum Command {
+    Forward,
+    Down,
+    Up,
+}
+
+impl Command {
+    /// The direction the command moves the submarine in part 1, where `y` is
+    /// the depth, so going forward is going right and going down adds to the
+    /// depth.
+    fn direction(self) -> Direction4 {
+        match self {
+            Command::Forward => Direction4::Right,
+            Command::Down => Direction4::Down,
+            Command::Up => Direction4::Up,
+        }
+    }
+}
+
+register_solver!();
+
 impl AoCSolver for Solver {
-    fn solve_part_1(&self) -> String {
-        let mut pos = 0;
-        let mut depth = 0;
+    fn solve_part_1(&self) -> Result<Answer> {
+        let mut position = Point2D::ORIGIN;
 
         for instruction in self.instructions.iter() {
-            match instruction {
-                Instruction::Up(amount) => depth -= amount,
-                Instruction::Down(amount) => depth += amount,
-                Instruction::Forward(amount) => pos += amount,
-            };
+            position += instruction.command.direction().offset() * instruction.amount;
         }
-        return (pos * depth).to_string();
+        return Ok((position.x * position.y).into());
     }
 
-    fn solve_part_2(&self) -> String {
-        let mut pos = 0;
-        let mut depth = 0;
+    fn solve_part_2(&self) -> Result<Answer> {
+        let mut position = Point2D::ORIGIN;
         let mut aim = 0;
 
         for instruction in self.instructions.iter() {
-            match instruction {
-                Instruction::Up(amount) => aim -= amount,
-                Instruction::Down(amount) => aim += amount,
-                Instruction::Forward(amount) => {
-                    pos += amount;
-                    depth += amount * aim
-                }
+            let amount = instruction.amount;
+            match instruction.command {
+                Command::Forward => position += Point2D::new(amount, amount * aim),
+                Command::Down => aim += amount,
+                Command::Up => aim -= amount,
             }
         }
-        return (pos * depth).to_string();
+        return Ok((position.x * position.y).into());
     }
 }
 
-pub fn parse_input() -> Vec<Instruction> {
-    let file = File::open("src/2021/day_02.txt").unwrap();
-    let reader = BufReader::new(file);
+pub fn parse_input(input: &str) -> Result<Vec<Instruction>> {
     let mut instructions = Vec::<Instruction>::new();
 
-    for line in reader.lines() {
-        let line = line.unwrap();
-        let segements = line.split(" ").collect::<Vec<&str>>();
-        let (instruction, amount) = (segements[0], segements[1]);
-        let amount = amount.parse::<i64>().unwrap();
+    for (line_number, line) in numbered_lines(input) {
+        let (instruction, amount) = line.split_once(' ').at_line(line_number)?;
+        let amount = amount.parse::<isize>().at_line(line_number)?;
 
-        let instruction = match instruction {
-            "up" => Instruction::Up(amount),
-            "down" => Instruction::Down(amount),
-            "forward" => Instruction::Forward(amount),
-            _ => panic!("Unexpected instruction"),
+        let command = match instruction {
+            "up" => Command::Up,
+            "down" => Command::Down,
+            "forward" => Command::Forward,
+            _ => return Err(Error::parse(line_number, "unexpected instruction")),
         };
-        instructions.push(instruction);
+        instructions.push(Instruction { command, amount });
     }
-    return instructions;
+    return Ok(instructions);
 }
@@ -1,5 +1,13 @@
-use crate::solver::AoCSolver;
-use std::{fs::File, io::BufRead, io::BufReader};
+#![allow(
+    clippy::bool_comparison,
+    clippy::get_first,
+    clippy::needless_return,
+    clippy::ptr_arg,
+    clippy::unnecessary_cast,
+)]
+
+use crate::error::Result;
+use crate::solver::{register_solver, Answer, AoCSolver};
 
 type BitVec = Vec<bool>;
 
@@ -8,15 +16,17 @@ pub struct Solver {
 }
 
 impl Solver {
-    pub fn create() -> Self {
-        Solver {
-            input: parse_input(),
-        }
+    pub fn from_input(input: &str) -> Result<Self> {
+        Ok(Solver {
+            input: parse_input(input),
+        })
     }
 }
 
+register_solver!();
+
 impl AoCSolver for Solver {
-    fn solve_part_1(&self) -> String {
+    fn solve_part_1(&self) -> Result<Answer> {
         let bit_count = self.input[0].len();
 
         // Find most common bit for each position
@@ -33,10 +43,10 @@ impl AoCSolver for Solver {
         //println!("Epsilon Rate Bits: {:?}", epsilon_rate_bits);
         // println!("Epsilon Rate: {:?}", epsilon_rate);
 
-        return (gamma_rate as u32 * epsilon_rate as u32).to_string();
+        return Ok((gamma_rate * epsilon_rate).into());
     }
 
-    fn solve_part_2(&self) -> String {
+    fn solve_part_2(&self) -> Result<Answer> {
         let bit_count = self.input[0].len();
 
         let mut working_set = self.input.clone();
@@ -45,7 +55,7 @@ impl AoCSolver for Solver {
             // println!("Position: {}", position);
             let most_common_bit = get_most_common_bit(&working_set, position);
             // println!("Most Common Bit: {}", most_common_bit);
-            let _ = working_set.extract_if(|x| x[position] != most_common_bit);
+            working_set.retain(|x| x[position] == most_common_bit);
             if working_set.len() == 1 {
                 break;
             }
@@ -63,7 +73,7 @@ impl AoCSolver for Solver {
             let most_common_bit = get_least_common_bit(&working_set, position);
             // println!("Least Common Bit: {}", most_common_bit);
 
-            let _ = working_set.extract_if(|x| x[position] != most_common_bit);
+            working_set.retain(|x| x[position] == most_common_bit);
             if working_set.len() == 1 {
                 break;
             }
@@ -74,7 +84,7 @@ impl AoCSolver for Solver {
         // println!("C02 Rating Bits: {:?}", c02_rating_bits);
         // println!("C02 Rating: {:?}", c02_rating);
 
-        return (oxygen_rating * c02_rating).to_string();
+        return Ok((oxygen_rating * c02_rating).into());
     }
 }
 
@@ -96,14 +106,10 @@ fn negate(bits: &BitVec) -> BitVec {
     return bits.iter().map(|x| !x).collect();
 }
 
-pub fn parse_input() -> Vec<BitVec> {
-    let file = File::open("src/2021/day_03.txt").unwrap();
-    let reader = BufReader::new(file);
-
+pub fn parse_input(input: &str) -> Vec<BitVec> {
     let mut bits_collection = vec![];
 
-    for line in reader.lines() {
-        let line = line.unwrap();
+    for line in input.lines() {
         let bits = line.chars().map(|c| c == '1').collect();
         bits_collection.push(bits);
     }
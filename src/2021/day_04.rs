@@ -1,5 +1,15 @@
-use crate::solver::AoCSolver;
-use std::{fs::File, io::BufRead, io::BufReader};
+#![allow(
+    clippy::comparison_to_empty,
+    clippy::get_first,
+    clippy::needless_borrow,
+    clippy::needless_range_loop,
+    clippy::needless_return,
+    clippy::ptr_arg,
+)]
+
+use crate::error::{Error, ParseContext, Result};
+use crate::input::numbered_lines;
+use crate::solver::{register_solver, Answer, AoCSolver};
 
 type Board = [[u8; 5]; 5];
 
@@ -9,14 +19,16 @@ pub struct Solver {
 }
 
 impl Solver {
-    pub fn create() -> Self {
-        let (numbers, boards) = parse_input();
-        return Solver { numbers, boards };
+    pub fn from_input(input: &str) -> Result<Self> {
+        let (numbers, boards) = parse_input(input)?;
+        return Ok(Solver { numbers, boards });
     }
 }
 
+register_solver!();
+
 impl AoCSolver for Solver {
-    fn solve_part_1(&self) -> String {
+    fn solve_part_1(&self) -> Result<Answer> {
         let mut numbers: Vec<u8> = self.numbers.iter().rev().cloned().collect();
         let mut called_numbers = vec![];
 
@@ -26,15 +38,15 @@ impl AoCSolver for Solver {
                 if has_board_won(&board, &called_numbers) {
                     let board_score = score_board(&board, &called_numbers);
                     let final_score = board_score * number as u32;
-                    return final_score.to_string();
+                    return Ok(final_score.into());
                 }
             }
         }
 
-        panic!("No solution found");
+        Err(Error::NoSolution)
     }
 
-    fn solve_part_2(&self) -> String {
+    fn solve_part_2(&self) -> Result<Answer> {
         let mut numbers: Vec<u8> = self.numbers.iter().rev().cloned().collect();
         let mut called_numbers = vec![];
         let mut boards = self.boards.clone();
@@ -48,15 +60,15 @@ impl AoCSolver for Solver {
                     let board_score = score_board(last_board_to_win, &called_numbers);
                     let final_score = board_score * number as u32;
                     // println!("{:?}", number);
-                    return final_score.to_string();
+                    return Ok(final_score.into());
                 }
             } else {
                 // Remove any board that wins
-                let _ = boards.extract_if(|board| has_board_won(&board, &called_numbers));
+                boards.retain(|board| !has_board_won(board, &called_numbers));
             }
         }
 
-        panic!("No solution found");
+        Err(Error::NoSolution)
     }
 }
 
@@ -90,25 +102,30 @@ fn score_board(board: &Board, numbers: &Vec<u8>) -> u32 {
         .sum()
 }
 
-fn parse_input() -> (Vec<u8>, Vec<Board>) {
-    let file = File::open("src/2021/day_04.txt").unwrap();
-    let reader = BufReader::new(file);
-
+fn parse_input(input: &str) -> Result<(Vec<u8>, Vec<Board>)> {
     let mut numbers = vec![];
     let mut boards = vec![];
     let mut current_board = [[0; 5]; 5];
     let mut current_board_row: usize = 0;
 
-    for (i, line) in reader.lines().enumerate() {
-        let line = line.unwrap();
-        if i == 0 {
-            numbers = line.split(',').map(|s| s.parse::<u8>().unwrap()).collect();
+    for (line_number, line) in numbered_lines(input) {
+        if line_number == 1 {
+            numbers = line
+                .split(',')
+                .map(|s| s.parse::<u8>().at_line(line_number))
+                .collect::<Result<_>>()?;
         } else if line != "" {
             let values: Vec<u8> = line
                 .split(' ')
                 .filter(|x| *x != "")
-                .map(|s| s.parse::<u8>().unwrap())
-                .collect();
+                .map(|s| s.parse::<u8>().at_line(line_number))
+                .collect::<Result<_>>()?;
+            if values.len() != 5 {
+                return Err(Error::parse(
+                    line_number,
+                    "expected 5 numbers per board row",
+                ));
+            }
             for (j, value) in values.iter().enumerate() {
                 current_board[current_board_row][j] = *value;
             }
@@ -121,5 +138,5 @@ fn parse_input() -> (Vec<u8>, Vec<Board>) {
             }
         }
     }
-    return (numbers, boards);
+    return Ok((numbers, boards));
 }
@@ -1,28 +1,26 @@
-use crate::solver::AoCSolver;
-use std::{fs::File, io::BufRead, io::BufReader};
+#![allow(clippy::needless_return)]
+
+use crate::error::{Error, ParseContext, Result};
+use crate::input::numbered_lines;
+use crate::solver::{register_solver, Answer, AoCSolver};
+use crate::util::point_2d::Point2D;
 
 pub struct Solver {
     line_segments: Vec<LineSegment>,
 }
 
 impl Solver {
-    pub fn create() -> Self {
-        Solver {
-            line_segments: parse_input(),
-        }
+    pub fn from_input(input: &str) -> Result<Self> {
+        Ok(Solver {
+            line_segments: parse_input(input)?,
+        })
     }
 }
 
-#[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct Point {
-    x: usize,
-    y: usize,
-}
-
 #[derive(Debug, PartialEq)]
 pub struct LineSegment {
-    start: Point,
-    end: Point,
+    start: Point2D,
+    end: Point2D,
 }
 
 impl LineSegment {
@@ -34,47 +32,29 @@ impl LineSegment {
         self.start.x == self.end.x
     }
 
-    fn get_points(&self) -> Vec<Point> {
-        let x_increment = LineSegment::get_increment(self.start.x, self.end.x);
-        let y_increment = LineSegment::get_increment(self.start.y, self.end.y);
+    fn get_points(&self) -> Vec<Point2D> {
+        // Lines are horizontal, vertical or diagonal, so this is a single step.
+        let increment = (self.end - self.start).reduced();
 
-        let mut x = self.start.x;
-        let mut y = self.start.y;
+        let mut point = self.start;
 
         let mut points = vec![];
 
-        while x != self.end.x || y != self.end.y {
-            points.push(Point { x, y });
-            x = LineSegment::add(x, x_increment);
-            y = LineSegment::add(y, y_increment);
+        while point != self.end {
+            points.push(point);
+            point += increment;
         }
 
-        points.push(self.end.clone());
+        points.push(self.end);
 
         return points;
     }
-
-    fn get_increment(left: usize, right: usize) -> isize {
-        if left < right {
-            1
-        } else if left > right {
-            -1
-        } else {
-            0
-        }
-    }
-
-    fn add(u: usize, i: isize) -> usize {
-        if i.is_negative() {
-            u - i.wrapping_abs() as usize
-        } else {
-            u + i as usize
-        }
-    }
 }
 
+register_solver!();
+
 impl AoCSolver for Solver {
-    fn solve_part_1(&self) -> String {
+    fn solve_part_1(&self) -> Result<Answer> {
         let non_diagonal_lines: Vec<&LineSegment> = self
             .line_segments
             .iter()
@@ -90,23 +70,23 @@ impl AoCSolver for Solver {
             non_diagonal_lines.iter().map(|l| l.end.y).max().unwrap(),
         );
 
-        let mut grid = vec![vec![0usize; max_x + 1]; max_y + 1];
+        let mut grid = vec![vec![0usize; max_x as usize + 1]; max_y as usize + 1];
 
         for line in non_diagonal_lines {
             for point in line.get_points() {
-                grid[point.y][point.x] += 1;
+                grid[point.y as usize][point.x as usize] += 1;
             }
         }
 
-        return grid
+        return Ok(grid
             .iter()
             .flat_map(|r| r.iter())
             .filter(|&&p| p >= 2)
             .count()
-            .to_string();
+            .into());
     }
 
-    fn solve_part_2(&self) -> String {
+    fn solve_part_2(&self) -> Result<Answer> {
         let max_x = std::cmp::max(
             self.line_segments.iter().map(|l| l.start.x).max().unwrap(),
             self.line_segments.iter().map(|l| l.end.x).max().unwrap(),
@@ -116,65 +96,68 @@ impl AoCSolver for Solver {
             self.line_segments.iter().map(|l| l.end.y).max().unwrap(),
         );
 
-        let mut grid = vec![vec![0usize; max_x + 1]; max_y + 1];
+        let mut grid = vec![vec![0usize; max_x as usize + 1]; max_y as usize + 1];
 
         for line in self.line_segments.iter() {
             for point in line.get_points() {
-                grid[point.y][point.x] += 1;
+                grid[point.y as usize][point.x as usize] += 1;
             }
         }
 
-        return grid
+        return Ok(grid
             .iter()
             .flat_map(|r| r.iter())
             .filter(|&&p| p >= 2)
             .count()
-            .to_string();
+            .into());
     }
 }
 
-pub fn parse_input() -> Vec<LineSegment> {
-    let file = File::open("src/2021/day_05.txt").unwrap();
-    let reader = BufReader::new(file);
-
+pub fn parse_input(input: &str) -> Result<Vec<LineSegment>> {
     let mut line_segments = vec![];
 
-    for line in reader.lines().map(|l| l.unwrap()) {
-        let values: Vec<usize> = line
+    for (line_number, line) in numbered_lines(input) {
+        let values: Vec<isize> = line
             .split("->")
             .flat_map(|x| x.split(","))
-            .map(|x| x.trim().parse().unwrap())
-            .collect();
+            .map(|x| x.trim().parse().at_line(line_number))
+            .collect::<Result<_>>()?;
+        if values.len() != 4 {
+            return Err(Error::parse(line_number, "expected 'x1,y1 -> x2,y2'"));
+        }
+        // The vents are counted on a grid starting at the origin.
+        if values.iter().any(|&value| value < 0) {
+            return Err(Error::parse(line_number, "coordinates can't be negative"));
+        }
         let line_segment = LineSegment {
-            start: Point {
-                x: values[0],
-                y: values[1],
-            },
-            end: Point {
-                x: values[2],
-                y: values[3],
-            },
+            start: Point2D::new(values[0], values[1]),
+            end: Point2D::new(values[2], values[3]),
         };
         line_segments.push(line_segment);
     }
-    return line_segments;
+    return Ok(line_segments);
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn parse_rejects_negative_coordinates() {
+        assert!(parse_input("0,9 -> 5,9\n8,0 -> 0,8").is_ok());
+        assert!(matches!(
+            parse_input("0,9 -> 5,9\n8,-1 -> 0,8"),
+            Err(Error::Parse { line: 2, .. })
+        ));
+    }
+
     #[test]
     fn get_points_horizontal() {
         let line = LineSegment {
-            start: Point { x: 3, y: 4 },
-            end: Point { x: 5, y: 4 },
+            start: Point2D::new(3, 4),
+            end: Point2D::new(5, 4),
         };
-        let expected = vec![
-            Point { x: 3, y: 4 },
-            Point { x: 4, y: 4 },
-            Point { x: 5, y: 4 },
-        ];
+        let expected = vec![Point2D::new(3, 4), Point2D::new(4, 4), Point2D::new(5, 4)];
 
         let actual = line.get_points();
 
@@ -184,14 +167,10 @@ mod tests {
     #[test]
     fn get_points_horizontal_rev() {
         let line = LineSegment {
-            end: Point { x: 5, y: 4 },
-            start: Point { x: 3, y: 4 },
+            end: Point2D::new(5, 4),
+            start: Point2D::new(3, 4),
         };
-        let expected = vec![
-            Point { x: 3, y: 4 },
-            Point { x: 4, y: 4 },
-            Point { x: 5, y: 4 },
-        ];
+        let expected = vec![Point2D::new(3, 4), Point2D::new(4, 4), Point2D::new(5, 4)];
 
         let actual = line.get_points();
 
@@ -201,14 +180,10 @@ mod tests {
     #[test]
     fn get_points_vertical() {
         let line = LineSegment {
-            start: Point { x: 4, y: 3 },
-            end: Point { x: 4, y: 5 },
+            start: Point2D::new(4, 3),
+            end: Point2D::new(4, 5),
         };
-        let expected = vec![
-            Point { x: 4, y: 3 },
-            Point { x: 4, y: 4 },
-            Point { x: 4, y: 5 },
-        ];
+        let expected = vec![Point2D::new(4, 3), Point2D::new(4, 4), Point2D::new(4, 5)];
 
         let actual = line.get_points();
 
@@ -218,14 +193,10 @@ mod tests {
     #[test]
     fn get_points_vertical_rev() {
         let line = LineSegment {
-            start: Point { x: 4, y: 5 },
-            end: Point { x: 4, y: 3 },
+            start: Point2D::new(4, 5),
+            end: Point2D::new(4, 3),
         };
-        let expected = vec![
-            Point { x: 4, y: 5 },
-            Point { x: 4, y: 4 },
-            Point { x: 4, y: 3 },
-        ];
+        let expected = vec![Point2D::new(4, 5), Point2D::new(4, 4), Point2D::new(4, 3)];
 
         let actual = line.get_points();
 
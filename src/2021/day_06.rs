@@ -1,25 +1,29 @@
-use crate::solver::AoCSolver;
-use std::{fs::File, io::BufRead, io::BufReader};
+#![allow(clippy::needless_return)]
+
+use crate::error::{Error, ParseContext, Result};
+use crate::solver::{register_solver, Answer, AoCSolver};
 
 pub struct Solver {
     timers: [u64; 9],
 }
 
 impl Solver {
-    pub fn create() -> Self {
-        Solver {
-            timers: parse_input(),
-        }
+    pub fn from_input(input: &str) -> Result<Self> {
+        Ok(Solver {
+            timers: parse_input(input)?,
+        })
     }
 }
 
+register_solver!();
+
 impl AoCSolver for Solver {
-    fn solve_part_1(&self) -> String {
-        simulate(&mut self.timers.clone(), 80).to_string()
+    fn solve_part_1(&self) -> Result<Answer> {
+        Ok(simulate(&mut self.timers.clone(), 80).into())
     }
 
-    fn solve_part_2(&self) -> String {
-        simulate(&mut self.timers.clone(), 256).to_string()
+    fn solve_part_2(&self) -> Result<Answer> {
+        Ok(simulate(&mut self.timers.clone(), 256).into())
     }
 }
 
@@ -36,22 +40,21 @@ fn simulate(timers: &mut [u64; 9], days: u32) -> u64 {
     return timers.iter().sum();
 }
 
-pub fn parse_input() -> [u64; 9] {
-    let file = File::open("src/2021/day_06.txt").unwrap();
-    let mut reader = BufReader::new(file);
-
-    let mut line = String::new();
-    reader.read_line(&mut line).unwrap();
+pub fn parse_input(input: &str) -> Result<[u64; 9]> {
+    let line = input.lines().next().at_line(1)?;
 
     let mut timer_groups = [0u64; 9];
     let timers: Vec<usize> = line
         .split(',')
-        .map(|timer| timer.parse().unwrap())
-        .collect();
+        .map(|timer| timer.parse().at_line(1))
+        .collect::<Result<_>>()?;
 
     for timer in timers {
+        if timer >= timer_groups.len() {
+            return Err(Error::parse(1, "timers must be between 0 and 8"));
+        }
         timer_groups[timer] += 1;
     }
 
-    return timer_groups;
+    return Ok(timer_groups);
 }
@@ -1,20 +1,24 @@
-use crate::solver::AoCSolver;
-use std::{fs::File, io::BufRead, io::BufReader};
+#![allow(clippy::cast_abs_to_unsigned, clippy::needless_return, clippy::ptr_arg)]
+
+use crate::error::{ParseContext, Result};
+use crate::solver::{register_solver, Answer, AoCSolver};
 
 pub struct Solver {
     positions: Vec<u32>,
 }
 
 impl Solver {
-    pub fn create() -> Self {
-        Solver {
-            positions: parse_input(),
-        }
+    pub fn from_input(input: &str) -> Result<Self> {
+        Ok(Solver {
+            positions: parse_input(input)?,
+        })
     }
 }
 
+register_solver!();
+
 impl AoCSolver for Solver {
-    fn solve_part_1(&self) -> String {
+    fn solve_part_1(&self) -> Result<Answer> {
         let max = self.positions.iter().max().unwrap();
 
         let best_cost = (0..*max)
@@ -22,10 +26,10 @@ impl AoCSolver for Solver {
             .min()
             .unwrap();
 
-        return best_cost.to_string();
+        return Ok(best_cost.into());
     }
 
-    fn solve_part_2(&self) -> String {
+    fn solve_part_2(&self) -> Result<Answer> {
         let max = self.positions.iter().max().unwrap();
 
         let best_cost = (0..*max)
@@ -33,7 +37,7 @@ impl AoCSolver for Solver {
             .min()
             .unwrap();
 
-        return best_cost.to_string();
+        return Ok(best_cost.into());
     }
 }
 
@@ -57,14 +61,10 @@ fn abs_diff(position: u32, destination: u32) -> u32 {
     ((destination as i32) - (position as i32)).abs() as u32
 }
 
-pub fn parse_input() -> Vec<u32> {
-    let file = File::open("src/2021/day_07.txt").unwrap();
-    let mut reader = BufReader::new(file);
-
-    let mut line = String::new();
-    reader.read_line(&mut line).unwrap();
+pub fn parse_input(input: &str) -> Result<Vec<u32>> {
+    let line = input.lines().next().at_line(1)?;
 
-    let positions = line.split(',').map(|pos| pos.parse().unwrap()).collect();
+    let positions = line.split(',').map(|pos| pos.parse().at_line(1)).collect();
 
     return positions;
 }
@@ -1,22 +1,29 @@
-use crate::solver::AoCSolver;
+#![allow(clippy::needless_borrow, clippy::needless_return, clippy::ptr_arg)]
+
+use crate::error::{Error, ParseContext, Result};
+use crate::input::numbered_lines;
+use crate::solver::{register_solver, Answer, AoCSolver};
 use itertools::Itertools;
-use std::{collections::HashMap, fs::File, io::BufRead, io::BufReader};
+use std::collections::HashMap;
 
 pub struct Solver {
     entries: Vec<Entry>,
 }
 
 impl Solver {
-    pub fn create() -> Self {
-        Solver {
-            entries: parse_input(),
-        }
+    pub fn from_input(input: &str) -> Result<Self> {
+        Ok(Solver {
+            entries: parse_input(input)?,
+        })
     }
 }
 
+register_solver!();
+
 impl AoCSolver for Solver {
-    fn solve_part_1(&self) -> String {
-        self.entries
+    fn solve_part_1(&self) -> Result<Answer> {
+        Ok(self
+            .entries
             .iter()
             .map(|e| {
                 e.output_values
@@ -25,20 +32,21 @@ impl AoCSolver for Solver {
                     .count()
             })
             .sum::<usize>()
-            .to_string()
+            .into())
     }
 
-    fn solve_part_2(&self) -> String {
-        self.entries
+    fn solve_part_2(&self) -> Result<Answer> {
+        Ok(self
+            .entries
             .iter()
             .map(|entry| {
-                let key = find_key(entry).unwrap();
+                let key = find_key(entry).map_err(|_| Error::NoSolution)?;
                 let output_value = calculate_output_value(entry, &key);
                 // println!("{:?}: {}", entry.output_values, output_value);
-                return output_value;
+                return Ok(output_value);
             })
-            .sum::<u32>()
-            .to_string()
+            .sum::<Result<u32>>()?
+            .into())
     }
 }
 
@@ -106,16 +114,12 @@ fn digits_to_number(digits: &Vec<u8>) -> u32 {
         .sum()
 }
 
-pub fn parse_input() -> Vec<Entry> {
-    let file = File::open("src/2021/day_08.txt").unwrap();
-    let reader = BufReader::new(file);
-
+pub fn parse_input(input: &str) -> Result<Vec<Entry>> {
     let mut entries = vec![];
 
-    for line in reader.lines() {
-        let line = line.unwrap();
-
-        let (signal_patterns, output_values) = line.split('|').collect_tuple().unwrap();
+    for (line_number, line) in numbered_lines(input) {
+        let (signal_patterns, output_values) =
+            line.split('|').collect_tuple().at_line(line_number)?;
         let signal_patterns = signal_patterns
             .trim()
             .split(' ')
@@ -134,5 +138,5 @@ pub fn parse_input() -> Vec<Entry> {
         entries.push(entry);
     }
 
-    return entries;
+    return Ok(entries);
 }
@@ -1,7 +1,11 @@
+#![allow(clippy::match_like_matches_macro, clippy::needless_return)]
+
 use itertools::Itertools;
 
-use crate::solver::AoCSolver;
-use std::{collections::VecDeque, fs::File, io::BufRead, io::BufReader};
+use crate::error::{Error, Result};
+use crate::input::numbered_lines;
+use crate::solver::{register_solver, Answer, AoCSolver};
+use std::collections::VecDeque;
 
 struct LineScore {
     corrupt_char_score: u64,
@@ -13,23 +17,26 @@ pub struct Solver {
 }
 
 impl Solver {
-    pub fn create() -> Self {
-        Solver {
-            lines: parse_input(),
-        }
+    pub fn from_input(input: &str) -> Result<Self> {
+        Ok(Solver {
+            lines: parse_input(input)?,
+        })
     }
 }
 
+register_solver!();
+
 impl AoCSolver for Solver {
-    fn solve_part_1(&self) -> String {
-        self.lines
+    fn solve_part_1(&self) -> Result<Answer> {
+        Ok(self
+            .lines
             .iter()
             .map(|l| score_line(l).corrupt_char_score)
             .sum::<u64>()
-            .to_string()
+            .into())
     }
 
-    fn solve_part_2(&self) -> String {
+    fn solve_part_2(&self) -> Result<Answer> {
         let sorted_scores: Vec<u64> = self
             .lines
             .iter()
@@ -38,7 +45,7 @@ impl AoCSolver for Solver {
             .sorted()
             .collect();
         let middle_score = sorted_scores[(sorted_scores.len() - 1) / 2];
-        return middle_score.to_string();
+        return Ok(middle_score.into());
     }
 }
 
@@ -129,8 +136,16 @@ fn score_incomplete_char(char: char) -> u64 {
     }
 }
 
-pub fn parse_input() -> Vec<String> {
-    let file = File::open("src/2021/day_10.txt").unwrap();
-    let reader = BufReader::new(file);
-    return reader.lines().map(|l| l.unwrap()).collect();
+pub fn parse_input(input: &str) -> Result<Vec<String>> {
+    return numbered_lines(input)
+        .map(
+            |(line_number, line)| match line.chars().find(|c| !"()[]{}<>".contains(*c)) {
+                Some(c) => Err(Error::parse(
+                    line_number,
+                    format!("unexpected char '{}'", c),
+                )),
+                None => Ok(String::from(line)),
+            },
+        )
+        .collect();
 }
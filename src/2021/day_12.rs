@@ -1,10 +1,10 @@
-use crate::solver::AoCSolver;
-use std::{
-    collections::{HashMap, VecDeque},
-    fs::File,
-    io::BufRead,
-    io::BufReader,
-};
+#![allow(clippy::needless_return)]
+
+use crate::error::{Error, ParseContext, Result};
+use crate::input::numbered_lines;
+use crate::solver::{register_solver, Answer, AoCSolver};
+use crate::util::graph::count_paths;
+use std::collections::HashMap;
 
 #[allow(dead_code)] // name_map is not used, but would be used if the chain was printed out for debugging purposes
 pub struct Solver {
@@ -16,83 +16,100 @@ pub struct Solver {
 }
 
 impl Solver {
-    pub fn create() -> Self {
-        let file = File::open("src/2021/day_12.txt").unwrap();
-        let reader = BufReader::new(file);
-
+    pub fn from_input(input: &str) -> Result<Self> {
         let mut cave_connections = Vec::new();
         let mut cave_is_small_map = Vec::new();
         let mut id_to_name = Vec::new();
         let mut name_to_id = HashMap::new();
 
-        cave_connections.resize(12, Vec::new());
-        cave_is_small_map.resize(12, false);
-        id_to_name.resize(12, String::from(""));
-
-        for line in reader.lines() {
-            let line = line.unwrap();
-            let (left, right) = line.split_once('-').unwrap();
+        for (line_number, line) in numbered_lines(input) {
+            let (left, right) = line.split_once('-').at_line(line_number)?;
 
             let next_id = name_to_id.len();
             let left_id = *name_to_id.entry(left.to_string()).or_insert(next_id);
             let next_id = name_to_id.len();
             let right_id = *name_to_id.entry(right.to_string()).or_insert(next_id);
 
-            id_to_name[left_id] = left.to_string();
+            for (id, name) in [(left_id, left), (right_id, right)].iter() {
+                if *id == id_to_name.len() {
+                    id_to_name.push(name.to_string());
+                    cave_connections.push(Vec::new());
+                    cave_is_small_map.push(is_cave_small(name));
+                }
+            }
+            // The caves a path has visited are kept as the bits of a u64.
+            if id_to_name.len() > 64 {
+                return Err(Error::parse(line_number, "more than 64 caves"));
+            }
             cave_connections[left_id].push(right_id);
-            cave_is_small_map[left_id] = is_cave_small(left);
-
-            id_to_name[right_id] = right.to_string();
             cave_connections[right_id].push(left_id);
-            cave_is_small_map[right_id] = is_cave_small(right);
         }
 
-        let start_id = name_to_id["start"];
-        let end_id = name_to_id["end"];
+        let start_id = *name_to_id.get("start").ok_or(Error::NoSolution)?;
+        let end_id = *name_to_id.get("end").ok_or(Error::NoSolution)?;
 
-        Solver {
+        Ok(Solver {
             cave_connections,
             cave_is_small_map,
             name_map: id_to_name,
             start_id,
             end_id,
-        }
+        })
     }
 
-    fn explore(
-        &self,
-        cave: usize,
-        chain: &mut VecDeque<usize>,
-        can_re_explore_small_cave: bool,
-        have_re_explored_cave: bool,
-    ) -> u32 {
-        let mut exit_path_count = 0;
-        chain.push_back(cave);
-        for &connected_cave in self.cave_connections[cave].iter() {
-            if connected_cave == self.start_id {
-                continue;
-            }
-            if connected_cave == self.end_id {
-                // println!("{:?}", chain);
-                exit_path_count += 1;
-                continue;
-            }
-            if self.cave_is_small_map[connected_cave] && chain.contains(&connected_cave) {
-                if can_re_explore_small_cave && !have_re_explored_cave {
-                    exit_path_count +=
-                        self.explore(connected_cave, chain, can_re_explore_small_cave, true);
+    /// Counts the paths from the start to the end that visit small caves at
+    /// most once, except for a single one that may be visited twice when
+    /// `can_re_explore_small_cave` is set.
+    fn count_exit_paths(&self, can_re_explore_small_cave: bool) -> usize {
+        let start = Step {
+            cave: self.start_id,
+            small_caves_visited: self.small_cave_bit(self.start_id),
+            have_re_explored_cave: false,
+        };
+        count_paths(
+            start,
+            |step| step.cave == self.end_id,
+            |step| {
+                let step = *step;
+                self.cave_connections[step.cave]
+                    .iter()
+                    .map(move |&cave| Step {
+                        cave,
+                        small_caves_visited: step.small_caves_visited | self.small_cave_bit(cave),
+                        have_re_explored_cave: step.have_re_explored_cave || step.has_visited(cave),
+                    })
+            },
+            |chain, next| {
+                let step = chain[chain.len() - 1];
+                if next.cave == self.start_id {
+                    return false;
                 }
-                continue;
-            }
-            exit_path_count += self.explore(
-                connected_cave,
-                chain,
-                can_re_explore_small_cave,
-                have_re_explored_cave,
-            );
+                !step.has_visited(next.cave)
+                    || (can_re_explore_small_cave && !step.have_re_explored_cave)
+            },
+        )
+    }
+
+    fn small_cave_bit(&self, cave: usize) -> u64 {
+        if self.cave_is_small_map[cave] {
+            1 << cave
+        } else {
+            0
         }
-        chain.pop_back();
-        return exit_path_count;
+    }
+}
+
+/// A cave on a path, with what the path has done to get there.
+#[derive(Clone, Copy)]
+struct Step {
+    cave: usize,
+    small_caves_visited: u64,
+    have_re_explored_cave: bool,
+}
+
+impl Step {
+    fn has_visited(self, cave: usize) -> bool {
+        self.small_caves_visited & 1 << cave != 0
     }
 }
 
@@ -100,15 +117,16 @@ fn is_cave_small(cave: &str) -> bool {
     cave.to_lowercase() == cave
 }
 
+register_solver!();
+
 impl AoCSolver for Solver {
-    fn solve_part_1(&self) -> String {
-        // println!("{:?}", self.cave_connections);
-        let exit_path_count = self.explore(self.start_id, &mut VecDeque::new(), false, false);
-        return exit_path_count.to_string();
+    fn solve_part_1(&self) -> Result<Answer> {
+        let exit_path_count = self.count_exit_paths(false);
+        return Ok(exit_path_count.into());
     }
 
-    fn solve_part_2(&self) -> String {
-        let exit_path_count = self.explore(self.start_id, &mut VecDeque::new(), true, false);
-        return exit_path_count.to_string();
+    fn solve_part_2(&self) -> Result<Answer> {
+        let exit_path_count = self.count_exit_paths(true);
+        return Ok(exit_path_count.into());
     }
 }
@@ -1,79 +1,77 @@
-use crate::solver::AoCSolver;
-use std::{collections::HashSet, fs::File, io::BufRead, io::BufReader};
+#![allow(clippy::comparison_to_empty, clippy::needless_return)]
 
-pub enum Fold {
-    X(usize),
-    Y(usize),
-}
+use crate::error::{Error, ParseContext, Result};
+use crate::input::numbered_lines;
+use crate::solver::{register_solver, Answer, AoCSolver};
+use crate::util::point_2d::Point2D;
+use std::collections::HashSet;
 
-#[derive(Clone, Debug, Eq, Hash, PartialEq)]
-struct Point {
-    x: usize,
-    y: usize,
+pub enum Fold {
+    X(isize),
+    Y(isize),
 }
 
 pub struct Solver {
-    points: Vec<Point>,
+    points: Vec<Point2D>,
     folds: Vec<Fold>,
 }
 
 impl Solver {
-    pub fn create() -> Self {
-        let file = File::open("src/2021/day_13.txt").unwrap();
-        let reader = BufReader::new(file);
-
+    pub fn from_input(input: &str) -> Result<Self> {
         let mut points = Vec::new();
         let mut folds = Vec::new();
 
-        for line in reader.lines() {
-            let line = line.unwrap();
-
+        for (line_number, line) in numbered_lines(input) {
             if line == "" {
             } else if line.starts_with("fold") {
-                let (dir, coord) = line.split_once('=').unwrap();
-                let coord = coord.parse().unwrap();
+                let (dir, coord) = line.split_once('=').at_line(line_number)?;
+                let coord = coord.parse().at_line(line_number)?;
                 let fold = match dir {
                     "fold along x" => Fold::X(coord),
                     "fold along y" => Fold::Y(coord),
-                    _ => panic!("Unexpected value"),
+                    _ => return Err(Error::parse(line_number, "unexpected fold direction")),
                 };
                 folds.push(fold);
             } else {
-                let (x, y) = line.split_once(',').unwrap();
-                let (x, y) = (x.parse().unwrap(), y.parse().unwrap());
-                points.push(Point { x, y });
+                let (x, y) = line.split_once(',').at_line(line_number)?;
+                let (x, y) = (
+                    x.parse().at_line(line_number)?,
+                    y.parse().at_line(line_number)?,
+                );
+                points.push(Point2D::new(x, y));
             }
         }
-        Solver { points, folds }
+        Ok(Solver { points, folds })
     }
 }
 
+register_solver!();
+
 impl AoCSolver for Solver {
-    fn solve_part_1(&self) -> String {
+    fn solve_part_1(&self) -> Result<Answer> {
         let mut points = self.points.clone();
-        let first_fold = self.folds.get(0).unwrap();
+        let first_fold = self.folds.first().ok_or(Error::NoSolution)?;
         transform_points(&mut points, first_fold);
         let visible_count = count_unique(&points);
-        return visible_count.to_string();
+        return Ok(visible_count.into());
     }
 
-    fn solve_part_2(&self) -> String {
+    fn solve_part_2(&self) -> Result<Answer> {
         let mut points = self.points.clone();
         for fold in self.folds.iter() {
             transform_points(&mut points, fold);
         }
-        // print_points(&points);
-        return String::from("UFRZKAUZ");
+        return Ok(render_points(&points));
     }
 }
 
-fn transform_points(points: &mut Vec<Point>, fold: &Fold) {
+fn transform_points(points: &mut [Point2D], fold: &Fold) {
     for point in points.iter_mut() {
         transform_point(point, fold);
     }
 }
 
-fn transform_point(point: &mut Point, fold: &Fold) {
+fn transform_point(point: &mut Point2D, fold: &Fold) {
     point.x = match fold {
         Fold::X(fold_line) => transform_value(point.x, *fold_line),
         Fold::Y(_) => point.x,
@@ -84,7 +82,7 @@ fn transform_point(point: &mut Point, fold: &Fold) {
     };
 }
 
-fn transform_value(value: usize, fold_line: usize) -> usize {
+fn transform_value(value: isize, fold_line: isize) -> isize {
     if value > fold_line {
         fold_line - (value - fold_line)
     } else {
@@ -92,25 +90,19 @@ fn transform_value(value: usize, fold_line: usize) -> usize {
     }
 }
 
-fn count_unique(points: &Vec<Point>) -> usize {
-    points.iter().cloned().collect::<HashSet<Point>>().len()
+fn count_unique(points: &[Point2D]) -> usize {
+    points.iter().cloned().collect::<HashSet<Point2D>>().len()
 }
 
-#[allow(dead_code)]
-fn print_points(points: &Vec<Point>) {
-    let width = points.iter().map(|p| p.x).max().unwrap() + 1;
-    let height = points.iter().map(|p| p.y).max().unwrap() + 1;
+fn render_points(points: &[Point2D]) -> Answer {
+    let width = points.iter().map(|p| p.x).max().unwrap_or(0) as usize + 1;
+    let height = points.iter().map(|p| p.y).max().unwrap_or(0) as usize + 1;
 
     let mut grid = vec![vec![false; width]; height];
 
     for point in points.iter() {
-        grid[point.y][point.x] = true;
+        grid[point.y as usize][point.x as usize] = true;
     }
 
-    for row in grid.iter() {
-        for cell in row.iter() {
-            print!("{}", if *cell { '#' } else { ' ' });
-        }
-        println!();
-    }
+    return Answer::art(&grid);
 }
@@ -1,45 +1,41 @@
-use std::{
-    collections::HashMap,
-    fs::File,
-    io::{BufRead, BufReader},
-    iter,
-};
+#![allow(clippy::needless_borrow, clippy::needless_return)]
+
+use std::{collections::HashMap, iter};
 
 use itertools::Itertools;
 
-use crate::solver::AoCSolver;
+use crate::error::{ParseContext, Result};
+use crate::input::numbered_lines;
+use crate::solver::{Answer, Day};
 
-struct InputData {
+pub struct InputData {
     left_numbers: Vec<usize>,
     right_numbers: Vec<usize>,
 }
 
-pub struct Solver {
-    input_data: InputData,
-}
+pub struct Solver;
+
+impl Day for Solver {
+    type Input = InputData;
 
-impl Solver {
-    pub fn create() -> Self {
-        let input_data = parse_input();
-        Solver { input_data }
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
     }
-}
 
-impl AoCSolver for Solver {
-    fn solve_part_1(&self) -> String {
-        let left_sorted = self.input_data.left_numbers.iter().sorted();
-        let right_sorted = self.input_data.right_numbers.iter().sorted();
+    fn part_1(input_data: &Self::Input) -> Result<Answer> {
+        let left_sorted = input_data.left_numbers.iter().sorted();
+        let right_sorted = input_data.right_numbers.iter().sorted();
 
         let sum: usize = iter::zip(left_sorted, right_sorted)
             .map(|(l, r)| l.abs_diff(*r))
             .sum();
 
-        return sum.to_string();
+        return Ok(sum.into());
     }
 
-    fn solve_part_2(&self) -> String {
+    fn part_2(input_data: &Self::Input) -> Result<Answer> {
         let frequencies =
-            self.input_data
+            input_data
                 .right_numbers
                 .iter()
                 .copied()
@@ -48,39 +44,35 @@ impl AoCSolver for Solver {
                     map
                 });
 
-        let sum: usize = self
-            .input_data
+        let sum: usize = input_data
             .left_numbers
             .iter()
             .map(|x| x * frequencies.get(x).unwrap_or(&0))
             .sum();
 
-        return sum.to_string();
+        return Ok(sum.into());
     }
 }
 
-fn parse_input() -> InputData {
-    let file = File::open("src/2024/day_01.txt").unwrap();
-    let reader = BufReader::new(file);
-
+fn parse_input(input: &str) -> Result<InputData> {
     let mut left_numbers = Vec::new();
     let mut right_numbers = Vec::new();
 
-    for line in reader.lines() {
-        let (left, right) = parse_line(line.unwrap().as_str());
+    for (line_number, line) in numbered_lines(input) {
+        let (left, right) = parse_line(line).at_line(line_number)?;
         left_numbers.push(left);
         right_numbers.push(right);
     }
 
-    return InputData {
+    return Ok(InputData {
         left_numbers,
         right_numbers,
-    };
+    });
 }
 
-fn parse_line(line: &str) -> (usize, usize) {
-    let spl: Vec<&str> = line.split_whitespace().collect();
-    return (spl[0].parse().unwrap(), spl[1].parse().unwrap());
+fn parse_line(line: &str) -> Option<(usize, usize)> {
+    let mut spl = line.split_whitespace();
+    return Some((spl.next()?.parse().ok()?, spl.next()?.parse().ok()?));
 }
 
 #[cfg(test)]
@@ -90,7 +82,7 @@ mod tests {
     #[test]
     fn parse_line_works() {
         let line = "123  456";
-        let actual = parse_line(&line);
+        let actual = parse_line(&line).unwrap();
         let expected = (123, 456);
         assert_eq!(actual, expected);
     }
@@ -1,34 +1,36 @@
+#![allow(clippy::manual_range_contains, clippy::needless_return)]
+
 use itertools::Itertools;
-use std::{
-    fs::File,
-    io::{BufRead, BufReader},
-};
 
-use crate::solver::AoCSolver;
+use crate::error::{ParseContext, Result};
+use crate::input::numbered_lines;
+use crate::solver::{register_solver, Answer, AoCSolver};
 
 pub struct Solver {
     reports: Vec<Vec<usize>>,
 }
 
 impl Solver {
-    pub fn create() -> Self {
-        let reports = parse_input();
-        Solver { reports }
+    pub fn from_input(input: &str) -> Result<Self> {
+        let reports = parse_input(input)?;
+        Ok(Solver { reports })
     }
 }
 
+register_solver!();
+
 impl AoCSolver for Solver {
-    fn solve_part_1(&self) -> String {
-        return self
+    fn solve_part_1(&self) -> Result<Answer> {
+        return Ok(self
             .reports
             .iter()
             .filter(|r| is_report_safe(r))
             .count()
-            .to_string();
+            .into());
     }
 
-    fn solve_part_2(&self) -> String {
-        return self
+    fn solve_part_2(&self) -> Result<Answer> {
+        return Ok(self
             .reports
             .iter()
             .filter(|r| {
@@ -38,7 +40,7 @@ impl AoCSolver for Solver {
                     .any(|c| is_report_safe(&c))
             })
             .count()
-            .to_string();
+            .into());
     }
 }
 
@@ -54,15 +56,11 @@ fn is_level_change_safe(left: usize, right: usize) -> bool {
     return diff >= 1 && diff <= 3;
 }
 
-fn parse_input() -> Vec<Vec<usize>> {
-    let file = File::open("src/2024/day_02.txt").unwrap();
-    let reader = BufReader::new(file);
-    let reports = reader
-        .lines()
-        .map(|line| {
-            line.unwrap()
-                .split_whitespace()
-                .map(|l| l.parse().unwrap())
+fn parse_input(input: &str) -> Result<Vec<Vec<usize>>> {
+    let reports = numbered_lines(input)
+        .map(|(line_number, line)| {
+            line.split_whitespace()
+                .map(|l| l.parse().at_line(line_number))
                 .collect()
         })
         .collect();
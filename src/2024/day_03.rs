@@ -1,4 +1,7 @@
-use crate::solver::AoCSolver;
+#![allow(clippy::needless_return)]
+
+use crate::error::Result;
+use crate::solver::{register_solver, Answer, AoCSolver};
 use nom::{
     branch::alt,
     bytes::complete::tag,
@@ -8,11 +11,7 @@ use nom::{
     sequence::{delimited, separated_pair},
     IResult,
 };
-use std::{
-    fs::File,
-    io::{BufRead, BufReader},
-    str::FromStr,
-};
+use std::str::FromStr;
 
 #[derive(Debug, PartialEq)]
 enum Instruction {
@@ -26,26 +25,26 @@ pub struct Solver {
 }
 
 impl Solver {
-    pub fn create() -> Self {
-        let file = File::open("src/2024/day_03.txt").unwrap();
-        let reader = BufReader::new(file);
-        let instructions = reader
+    pub fn from_input(input: &str) -> Result<Self> {
+        let instructions = input
             .lines()
             .flat_map(|line| {
-                if let Ok((_, x)) = parse_instructions(&line.unwrap()) {
+                if let Ok((_, x)) = parse_instructions(line) {
                     x
                 } else {
                     Vec::new()
                 }
             })
             .collect();
-        Solver { instructions }
+        Ok(Solver { instructions })
     }
 }
 
+register_solver!();
+
 impl AoCSolver for Solver {
-    fn solve_part_1(&self) -> String {
-        return self
+    fn solve_part_1(&self) -> Result<Answer> {
+        return Ok(self
             .instructions
             .iter()
             .map(|instruction| match instruction {
@@ -54,12 +53,12 @@ impl AoCSolver for Solver {
                 Instruction::Dont => 0,
             })
             .sum::<usize>()
-            .to_string();
+            .into());
     }
 
-    fn solve_part_2(&self) -> String {
+    fn solve_part_2(&self) -> Result<Answer> {
         let mut do_mul = true;
-        return self
+        return Ok(self
             .instructions
             .iter()
             .map(|instruction| match instruction {
@@ -80,7 +79,7 @@ impl AoCSolver for Solver {
                 }
             })
             .sum::<usize>()
-            .to_string();
+            .into());
     }
 }
 
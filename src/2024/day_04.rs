@@ -1,100 +1,70 @@
-use crate::solver::AoCSolver;
-use std::{fs::File, io::BufRead, io::BufReader};
+#![allow(clippy::needless_return)]
 
-type Grid = Vec<Vec<char>>;
+use crate::error::Result;
+use crate::solver::{register_solver, Answer, AoCSolver};
+use crate::util::direction::Direction8;
+use crate::util::grid::Grid;
+use crate::util::point_2d::Point2D;
 
 pub struct Solver {
-    grid: Grid,
+    grid: Grid<char>,
 }
 
 impl Solver {
-    pub fn create() -> Self {
-        Solver {
-            grid: parse_input(),
-        }
+    pub fn from_input(input: &str) -> Result<Self> {
+        Ok(Solver {
+            grid: parse_input(input)?,
+        })
     }
 }
 
-impl AoCSolver for Solver {
-    fn solve_part_1(&self) -> String {
-        let mut count = 0;
-        for y in 0..self.grid.len() {
-            for x in 0..self.grid[y].len() {
-                count += search_for_xmas_in_direction(&self.grid, x, y, 1, 0);
-                count += search_for_xmas_in_direction(&self.grid, x, y, -1, 0);
-                count += search_for_xmas_in_direction(&self.grid, x, y, 0, 1);
-                count += search_for_xmas_in_direction(&self.grid, x, y, 0, -1);
-                count += search_for_xmas_in_direction(&self.grid, x, y, 1, 1);
-                count += search_for_xmas_in_direction(&self.grid, x, y, 1, -1);
-                count += search_for_xmas_in_direction(&self.grid, x, y, -1, 1);
-                count += search_for_xmas_in_direction(&self.grid, x, y, -1, -1);
-            }
-        }
-        return count.to_string();
-    }
+register_solver!();
 
-    fn solve_part_2(&self) -> String {
+impl AoCSolver for Solver {
+    fn solve_part_1(&self) -> Result<Answer> {
         let mut count = 0;
-        for y in 0..self.grid.len() {
-            for x in 0..self.grid[y].len() {
-                if search_for_mas_x(&self.grid, x as isize, y as isize) {
+        for point in self.grid.find_all(&'X') {
+            for direction in Direction8::iter() {
+                if search_for_xmas_in_direction(&self.grid, point, direction.offset()) {
                     count += 1;
                 }
             }
         }
-        return count.to_string();
+        return Ok(count.into());
     }
-}
-
-fn search_for_xmas_in_direction(
-    grid: &Grid,
-    x_start: usize,
-    y_start: usize,
-    x_step: isize,
-    y_step: isize,
-) -> usize {
-    let mut x = x_start as isize;
-    let mut y = y_start as isize;
-    const EXPECTED: [char; 4] = ['X', 'M', 'A', 'S'];
-    let mut distance = 0;
-    let max_distance = EXPECTED.len();
-    loop {
-        let Some(character) = get_character(grid, x, y) else {
-            return 0;
-        };
-
-        if character != EXPECTED[distance] {
-            return 0;
-        }
 
-        x += x_step;
-        y += y_step;
-        distance += 1;
-
-        if distance == max_distance {
-            return 1;
-        }
+    fn solve_part_2(&self) -> Result<Answer> {
+        let count = self
+            .grid
+            .find_all(&'A')
+            .filter(|point| search_for_mas_x(&self.grid, *point))
+            .count();
+        return Ok(count.into());
     }
 }
 
-fn search_for_mas_x(grid: &Grid, x: isize, y: isize) -> bool {
-    let Some(center) = get_character(grid, x, y) else {
-        return false;
-    };
-    if center != 'A' {
-        return false;
+/// Whether the letters after the `X` at `start` spell out the rest of `XMAS`.
+fn search_for_xmas_in_direction(grid: &Grid<char>, start: Point2D, step: Point2D) -> bool {
+    const EXPECTED: [char; 3] = ['M', 'A', 'S'];
+    let mut point = start;
+    for expected in EXPECTED {
+        point += step;
+        if grid.get(point) != Some(&expected) {
+            return false;
+        }
     }
+    return true;
+}
 
-    let Some(top_left) = get_character(grid, x - 1, y - 1) else {
-        return false;
-    };
-    let Some(top_right) = get_character(grid, x + 1, y - 1) else {
-        return false;
-    };
-    let Some(bottom_left) = get_character(grid, x - 1, y + 1) else {
-        return false;
-    };
-    let Some(bottom_right) = get_character(grid, x + 1, y + 1) else {
+/// Whether the `A` at `center` has `MAS` crossing it along both diagonals.
+fn search_for_mas_x(grid: &Grid<char>, center: Point2D) -> bool {
+    let corner = |direction: Direction8| grid.get(center + direction.offset()).copied();
+    let (Some(top_left), Some(top_right), Some(bottom_left), Some(bottom_right)) = (
+        corner(Direction8::UpLeft),
+        corner(Direction8::UpRight),
+        corner(Direction8::DownLeft),
+        corner(Direction8::DownRight),
+    ) else {
         return false;
     };
 
@@ -102,21 +72,6 @@ fn search_for_mas_x(grid: &Grid, x: isize, y: isize) -> bool {
         && ((bottom_left == 'M' && top_right == 'S') || (bottom_left == 'S' && top_right == 'M'));
 }
 
-fn get_character(grid: &Grid, x: isize, y: isize) -> Option<char> {
-    // Check if the coordinates are out of bounds.
-    if x < 0 || x >= grid[0].len() as isize || y < 0 || y >= grid.len() as isize {
-        return None;
-    }
-
-    return Some(grid[y as usize][x as usize]);
-}
-
-pub fn parse_input() -> Grid {
-    let file = File::open("src/2024/day_04.txt").unwrap();
-    let reader = BufReader::new(file);
-    let grid = reader
-        .lines()
-        .map(|line| line.unwrap().chars().collect())
-        .collect();
-    return grid;
+pub fn parse_input(input: &str) -> Result<Grid<char>> {
+    return Grid::parse(input, Some);
 }
@@ -1,12 +1,15 @@
-use std::{
-    collections::HashSet,
-    fs::File,
-    io::{BufRead, BufReader},
-};
-
-use itertools::Itertools;
-
-use crate::solver::AoCSolver;
+#![allow(
+    clippy::comparison_to_empty,
+    clippy::needless_borrow,
+    clippy::needless_return,
+    clippy::ptr_arg,
+    clippy::redundant_closure,
+)]
+
+use crate::error::{Error, ParseContext, Result};
+use crate::input::numbered_lines;
+use crate::solver::{register_solver, Answer, AoCSolver};
+use crate::util::graph::topological_sort;
 
 type OrderingRule = (usize, usize);
 type PageCollection = Vec<usize>;
@@ -17,39 +20,36 @@ pub struct Solver {
 }
 
 impl Solver {
-    pub fn create() -> Self {
-        let (ordering_rules, updates) = parse_input();
-        Solver {
+    pub fn from_input(input: &str) -> Result<Self> {
+        let (ordering_rules, updates) = parse_input(input)?;
+        Ok(Solver {
             ordering_rules,
             updates,
-        }
+        })
     }
 }
 
+register_solver!();
+
 impl AoCSolver for Solver {
-    fn solve_part_1(&self) -> String {
+    fn solve_part_1(&self) -> Result<Answer> {
         let sum: usize = self
             .updates
             .iter()
             .filter(|x| check_is_sorted(x, &self.ordering_rules))
             .map(|x| get_middle_page(x))
             .sum();
-        return sum.to_string();
+        return Ok(sum.into());
     }
 
-    fn solve_part_2(&self) -> String {
-        let sum: usize = self
-            .updates
-            .iter()
-            .filter(|x| !check_is_sorted(x, &self.ordering_rules))
-            .map(|x| {
-                get_middle_page(&sort_pages_using_ordering_rule_elimination(
-                    x,
-                    &self.ordering_rules,
-                ))
-            })
-            .sum();
-        return sum.to_string();
+    fn solve_part_2(&self) -> Result<Answer> {
+        let mut sum = 0;
+        for pages in self.updates.iter() {
+            if !check_is_sorted(pages, &self.ordering_rules) {
+                sum += get_middle_page(&sort_pages(pages, &self.ordering_rules)?);
+            }
+        }
+        return Ok(sum.into());
     }
 }
 
@@ -71,73 +71,19 @@ fn get_middle_page(pages: &PageCollection) -> usize {
     pages[pages.len() / 2]
 }
 
-#[cfg(test)]
-fn sort_pages_using_swap(
-    pages: &PageCollection,
-    ordering_rules: &Vec<OrderingRule>,
-) -> PageCollection {
-    // Filter down ordering rules to just those which apply to our set of pages
-    let relevant_ordering_rules: Vec<&OrderingRule> = ordering_rules
-        .iter()
-        .filter(|(left, right)| pages.contains(&left) && pages.contains(&right))
-        .collect();
-
-    let mut pages = pages.clone();
-
-    // Finds the indexes of the pages of an ordering rule and swaps them if they are out of order
-    // If no swaps are made then the collection is in order and is returned
-    loop {
-        let mut sorted = true;
-        for (left, right) in relevant_ordering_rules.iter() {
-            let left_index = pages.iter().position(|x| x == left).unwrap();
-            let right_index = pages.iter().position(|x| x == right).unwrap();
-            if left_index > right_index {
-                pages.swap(left_index, right_index);
-                sorted = false;
-            }
-        }
-        if sorted {
-            break;
-        }
-    }
-
-    pages
-}
-
-fn sort_pages_using_ordering_rule_elimination(
+/// Sorts the pages by the ordering rules, which fails when the rules that
+/// apply to them contradict each other.
+fn sort_pages(
     pages: &PageCollection,
     ordering_rules: &Vec<OrderingRule>,
-) -> PageCollection {
-    // Filter down ordering rules to just those which apply to our set of pages
-    let mut relevant_ordering_rules: Vec<&OrderingRule> = ordering_rules
+) -> Result<PageCollection> {
+    // An update only has a couple of dozen pages, so searching them is
+    // cheaper than letting the sort hash both pages of every rule.
+    let relevant_ordering_rules = ordering_rules
         .iter()
-        .filter(|(left, right)| pages.contains(&left) && pages.contains(&right))
-        .collect();
-
-    let mut sorted = PageCollection::new();
-    let mut to_sort: HashSet<&usize> = pages.iter().collect();
-
-    for _ in pages {
-        // Find the page which doesn't have any rules which put after another page
-        let page = **to_sort
-            .iter()
-            .filter(|p| {
-                !relevant_ordering_rules
-                    .iter()
-                    .any(|(_, right)| **p == right)
-            })
-            .exactly_one()
-            .unwrap();
-        sorted.push(page);
-        to_sort.remove(&page);
-
-        // Remove ordering rules specific to the page we just sorted
-        let _: Vec<&OrderingRule> = relevant_ordering_rules
-            .extract_if(|(left, _)| left == &page)
-            .collect();
-    }
-
-    sorted
+        .filter(|(left, right)| pages.contains(left) && pages.contains(right))
+        .copied();
+    topological_sort(pages, relevant_ordering_rules).map_err(|_| Error::NoSolution)
 }
 
 enum ParseMode {
@@ -145,18 +91,13 @@ enum ParseMode {
     UpdateSet,
 }
 
-fn parse_input() -> (Vec<OrderingRule>, Vec<PageCollection>) {
-    let file = File::open("src/2024/day_05.txt").unwrap();
-    let reader = BufReader::new(file);
-
+fn parse_input(input: &str) -> Result<(Vec<OrderingRule>, Vec<PageCollection>)> {
     let mut ordering_rules = Vec::new();
     let mut updates = Vec::new();
 
     let mut parse_mode = ParseMode::OrderingRule;
 
-    for line in reader.lines() {
-        let line = line.unwrap();
-
+    for (line_number, line) in numbered_lines(input) {
         if line == "" {
             parse_mode = ParseMode::UpdateSet;
             continue;
@@ -164,24 +105,24 @@ fn parse_input() -> (Vec<OrderingRule>, Vec<PageCollection>) {
 
         match parse_mode {
             ParseMode::OrderingRule => {
-                ordering_rules.push(parse_ordering_rule(&line));
+                ordering_rules.push(parse_ordering_rule(line).at_line(line_number)?);
             }
             ParseMode::UpdateSet => {
-                updates.push(parse_update(&line));
+                updates.push(parse_update(line).at_line(line_number)?);
             }
         };
     }
 
-    (ordering_rules, updates)
+    Ok((ordering_rules, updates))
 }
 
-fn parse_ordering_rule(line: &str) -> OrderingRule {
-    let (left, right) = line.split_once('|').unwrap();
-    (left.parse().unwrap(), right.parse().unwrap())
+fn parse_ordering_rule(line: &str) -> Option<OrderingRule> {
+    let (left, right) = line.split_once('|')?;
+    Some((left.parse().ok()?, right.parse().ok()?))
 }
 
-fn parse_update(line: &str) -> PageCollection {
-    line.split(',').map(|x| x.parse().unwrap()).collect()
+fn parse_update(line: &str) -> Option<PageCollection> {
+    line.split(',').map(|x| x.parse().ok()).collect()
 }
 
 #[cfg(test)]
@@ -189,18 +130,11 @@ mod tests {
     use super::*;
 
     #[test]
-    fn sort_pages_using_swap_works() {
-        let pages = vec![3, 1, 2, 5];
-        let ordering_rules = vec![(1, 2), (2, 3), (3, 4), (4, 5), (3, 5)];
-        let ordered_pages = sort_pages_using_swap(&pages, &ordering_rules);
-        assert_eq!(ordered_pages, vec![1, 2, 3, 5]);
-    }
-
-    #[test]
-    fn sort_pages_using_ordering_rule_elimination_works() {
+    fn sort_pages_works() {
         let pages = vec![3, 1, 2, 5];
         let ordering_rules = vec![(1, 2), (2, 3), (3, 4), (4, 5), (3, 5)];
-        let ordered_pages = sort_pages_using_ordering_rule_elimination(&pages, &ordering_rules);
+        let ordered_pages = sort_pages(&pages, &ordering_rules).unwrap();
         assert_eq!(ordered_pages, vec![1, 2, 3, 5]);
+        assert!(sort_pages(&vec![1, 2], &vec![(1, 2), (2, 1)]).is_err());
     }
 }
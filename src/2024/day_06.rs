@@ -1,20 +1,14 @@
-use std::{
-    collections::HashSet,
-    fs::File,
-    io::{BufRead, BufReader},
-};
+#![allow(clippy::needless_return)]
 
-use crate::solver::AoCSolver;
+use std::collections::HashSet;
 
-type Position = (usize, usize);
+use crate::error::{Error, Result};
+use crate::solver::{register_solver, Answer, AoCSolver};
+use crate::util::direction::Direction4;
+use crate::util::grid::Grid;
+use crate::util::point_2d::Point2D;
 
-#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
-enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
-}
+type Position = Point2D;
 
 enum MoveResult {
     Success(Position),
@@ -29,106 +23,46 @@ enum PatrolResult {
 
 pub struct Solver {
     starting_position: Position,
-    obstacles: HashSet<Position>,
-    width: usize,
-    height: usize,
+    /// Whether each cell holds an obstacle.
+    obstacles: Grid<bool>,
 }
 
 impl Solver {
-    pub fn create() -> Self {
-        let file = File::open("src/2024/day_06.txt").unwrap();
-        let reader = BufReader::new(file);
-        let mut starting_position: Option<Position> = None;
-        let mut obstacles = HashSet::new();
-
-        let mut y = 0;
-        let mut width = 0;
-
-        for line in reader.lines() {
-            let line = line.unwrap();
-            width = line.len();
-
-            let mut x = 0;
-
-            for character in line.chars() {
-                if character == '#' {
-                    obstacles.insert((x, y));
-                } else if character == '^' {
-                    starting_position = Some((x, y));
-                }
-                x += 1;
-            }
-            y += 1;
-        }
-
-        let height = y - 1;
-        Solver {
-            starting_position: starting_position.expect("No starting position found"),
-            obstacles,
-            width,
-            height,
-        }
+    pub fn from_input(input: &str) -> Result<Self> {
+        let map = Grid::parse(input, |character| match character {
+            '.' | '#' | '^' => Some(character),
+            _ => None,
+        })?;
+        let starting_position = map
+            .find_all(&'^')
+            .next()
+            .ok_or_else(|| Error::parse(map.height(), "no starting position found"))?;
+
+        Ok(Solver {
+            starting_position,
+            obstacles: map.map(|&character| character == '#'),
+        })
     }
 
     fn move_in_direction(
         &self,
-        (x, y): &Position,
-        direction: &Direction,
+        position: &Position,
+        direction: &Direction4,
         new_obstacle: Option<Position>,
     ) -> MoveResult {
-        let (x, y) = (*x, *y);
-        let new_position = match direction {
-            Direction::Up => {
-                if y == 0 {
-                    return MoveResult::OutOfBounds;
-                } else {
-                    (x, y - 1)
-                }
-            }
-            Direction::Down => {
-                if y == self.height {
-                    return MoveResult::OutOfBounds;
-                } else {
-                    (x, y + 1)
-                }
-            }
-            Direction::Left => {
-                if x == 0 {
-                    return MoveResult::OutOfBounds;
-                } else {
-                    (x - 1, y)
-                }
-            }
-            Direction::Right => {
-                if x == self.width {
-                    return MoveResult::OutOfBounds;
-                } else {
-                    (x + 1, y)
-                }
-            }
-        };
-        if self.obstacles.contains(&new_position)
-            || new_obstacle.is_some_and(|obstacle| obstacle == new_position)
-        {
-            MoveResult::Obstacle
-        } else {
-            MoveResult::Success(new_position)
-        }
-    }
-
-    fn rotate(direction: &Direction) -> Direction {
-        match direction {
-            Direction::Up => Direction::Right,
-            Direction::Down => Direction::Left,
-            Direction::Left => Direction::Up,
-            Direction::Right => Direction::Down,
+        let new_position = *position + direction.offset();
+        match self.obstacles.get(new_position) {
+            None => MoveResult::OutOfBounds,
+            Some(true) => MoveResult::Obstacle,
+            Some(false) if new_obstacle == Some(new_position) => MoveResult::Obstacle,
+            Some(false) => MoveResult::Success(new_position),
         }
     }
 
     fn patrol(&self, new_obstacle: Option<Position>) -> PatrolResult {
         let mut visited_cells = HashSet::new();
         let mut position = self.starting_position;
-        let mut direction = Direction::Up;
+        let mut direction = Direction4::Up;
         visited_cells.insert((position, direction));
         loop {
             match self.move_in_direction(&position, &direction, new_obstacle) {
@@ -145,7 +79,7 @@ impl Solver {
                         //     "Obstacle found at {:?}. New direction is {:?}",
                         //     new_position, direction
                         // );
-                        Solver::rotate(&direction)
+                        direction.turn_right()
                     }
                 }
                 MoveResult::OutOfBounds => {
@@ -162,15 +96,17 @@ impl Solver {
     }
 }
 
+register_solver!();
+
 impl AoCSolver for Solver {
-    fn solve_part_1(&self) -> String {
+    fn solve_part_1(&self) -> Result<Answer> {
         match self.patrol(None) {
-            PatrolResult::Success(visited_positions) => return visited_positions.len().to_string(),
+            PatrolResult::Success(visited_positions) => return Ok(visited_positions.len().into()),
             PatrolResult::LoopDetected => panic!("Loop detected, that shouldn't happen here!"),
         }
     }
 
-    fn solve_part_2(&self) -> String {
+    fn solve_part_2(&self) -> Result<Answer> {
         let default_visited_cells = match self.patrol(None) {
             PatrolResult::Success(visited_positions) => visited_positions,
             PatrolResult::LoopDetected => panic!("Loop detected, that shouldn't happen here!"),
@@ -183,6 +119,6 @@ impl AoCSolver for Solver {
                 PatrolResult::LoopDetected => positions_which_cause_a_loop += 1,
             }
         }
-        return positions_which_cause_a_loop.to_string();
+        return Ok(positions_which_cause_a_loop.into());
     }
 }
@@ -1,11 +1,9 @@
-use std::{
-    fs::File,
-    io::{BufRead, BufReader},
-};
+#![allow(clippy::needless_return)]
 
 use itertools::Itertools;
 
-use crate::solver::AoCSolver;
+use crate::error::Result;
+use crate::solver::{register_solver, Answer, AoCSolver};
 use crate::util::point_2d::Point2D;
 
 #[derive(Clone, Copy, PartialEq)]
@@ -27,10 +25,8 @@ pub struct Solver {
 }
 
 impl Solver {
-    pub fn create() -> Self {
-        let file = File::open("src/2024/day_08.txt").unwrap();
-        let reader = BufReader::new(file);
-        let lines: Vec<String> = reader.lines().map(|line| line.unwrap()).collect();
+    pub fn from_input(input: &str) -> Result<Self> {
+        let lines: Vec<&str> = input.lines().collect();
         let mut antennas = Vec::new();
         for (y, line) in lines.iter().enumerate() {
             for (x, character) in line.chars().enumerate() {
@@ -39,19 +35,16 @@ impl Solver {
                 }
                 let antenna = Antenna {
                     frequency: character,
-                    position: Point2D {
-                        x: x as isize,
-                        y: y as isize,
-                    },
+                    position: Point2D::from_usize(x, y),
                 };
                 antennas.push(antenna);
             }
         }
-        Solver {
+        Ok(Solver {
             antennas,
             x_max: lines[0].len() - 1,
             y_max: lines.len() - 1,
-        }
+        })
     }
 
     fn count_antinodes(&self, antinode_generation_mode: AntinodeGenerationMode) -> usize {
@@ -104,7 +97,7 @@ impl Solver {
 
         let mut pos = pos;
         loop {
-            pos = pos - offset;
+            pos -= offset;
             if self.is_pos_in_bounds(pos) {
                 positions.push(pos);
             } else {
@@ -122,14 +115,14 @@ impl Solver {
     }
 }
 
+register_solver!();
+
 impl AoCSolver for Solver {
-    fn solve_part_1(&self) -> String {
-        self.count_antinodes(AntinodeGenerationMode::Part1)
-            .to_string()
+    fn solve_part_1(&self) -> Result<Answer> {
+        Ok(self.count_antinodes(AntinodeGenerationMode::Part1).into())
     }
 
-    fn solve_part_2(&self) -> String {
-        self.count_antinodes(AntinodeGenerationMode::Part2)
-            .to_string()
+    fn solve_part_2(&self) -> Result<Answer> {
+        Ok(self.count_antinodes(AntinodeGenerationMode::Part2).into())
     }
 }
@@ -1,10 +1,17 @@
-use std::{
-    fs::File,
-    io::{BufRead, BufReader},
-    iter,
-};
+#![allow(
+    clippy::double_ended_iterator_last,
+    clippy::needless_range_loop,
+    clippy::needless_return,
+    clippy::partialeq_to_none,
+    clippy::println_empty_string,
+    clippy::ptr_arg,
+    clippy::while_let_loop,
+)]
 
-use crate::solver::AoCSolver;
+use std::iter;
+
+use crate::error::{Error, ParseContext, Result};
+use crate::solver::{register_solver, Answer, AoCSolver};
 
 #[derive(Clone, Copy)]
 enum MemoryBlock {
@@ -17,12 +24,16 @@ pub struct Solver {
 }
 
 impl Solver {
-    pub fn create() -> Self {
-        let file = File::open("src/2024/day_09.txt").unwrap();
-        let reader = BufReader::new(file);
-
-        let first_line = reader.lines().next().unwrap().unwrap();
-        return Solver::from_dense_format(first_line.as_str());
+    pub fn from_input(input: &str) -> Result<Self> {
+        let mut lines = input.lines();
+        let first_line = lines.next().at_line(1)?;
+        if !first_line.chars().all(|c| c.is_ascii_digit()) {
+            return Err(Error::parse(1, "expected a string of digits"));
+        }
+        if lines.next().is_some() {
+            return Err(Error::parse(2, "expected the disk map on a single line"));
+        }
+        return Ok(Solver::from_dense_format(first_line));
     }
 
     pub fn from_dense_format(dense_memory: &str) -> Self {
@@ -50,7 +61,10 @@ impl Solver {
         let mut working_memory = self.memory.clone();
 
         let mut head_index = 0;
-        let mut tail_index = working_memory.len() - 1;
+        // An empty disk has nothing to move.
+        let Some(mut tail_index) = working_memory.len().checked_sub(1) else {
+            return working_memory;
+        };
 
         loop {
             // walk head pointer forward until it points to a free space
@@ -85,17 +99,20 @@ impl Solver {
 
         // Find id of last memory block. This will be the max id of all file blocks in the memory.
         // This value will be decremented during the defrag process to ensure blocks are not moved more than once.
-        let mut max_id = *working_memory
+        let Some(mut max_id) = working_memory
             .iter()
             .filter_map(|block| {
                 if let MemoryBlock::File(id) = block {
-                    Some(id)
+                    Some(*id)
                 } else {
                     None
                 }
             })
             .last()
-            .unwrap();
+        else {
+            // Without any files there is nothing to move.
+            return working_memory;
+        };
 
         let mut free_space_sections = find_free_space_sections(&working_memory);
 
@@ -229,6 +246,7 @@ fn calculate_checksum(memory: &Vec<MemoryBlock>) -> usize {
         .sum()
 }
 
+#[allow(dead_code)]
 fn print(memory: &Vec<MemoryBlock>) {
     for block in memory.iter() {
         print!(
@@ -242,17 +260,19 @@ fn print(memory: &Vec<MemoryBlock>) {
     println!("")
 }
 
+register_solver!();
+
 impl AoCSolver for Solver {
-    fn solve_part_1(&self) -> String {
+    fn solve_part_1(&self) -> Result<Answer> {
         let defragged = self.defrag();
         let checksum = calculate_checksum(&defragged);
-        return checksum.to_string();
+        return Ok(checksum.into());
     }
 
-    fn solve_part_2(&self) -> String {
+    fn solve_part_2(&self) -> Result<Answer> {
         let defragged = self.defrag_2();
         let checksum = calculate_checksum(&defragged);
-        return checksum.to_string();
+        return Ok(checksum.into());
     }
 }
 
@@ -260,27 +280,11 @@ impl AoCSolver for Solver {
 mod tests {
     use super::*;
 
-    #[test]
-    fn defrag_example() {
-        let solver = Solver::from_dense_format("2333133121414131402");
-        let defragged = solver.defrag();
-        print(&defragged);
-        let checksum = calculate_checksum(&defragged);
-        assert_eq!(checksum, 1928);
-    }
-
-    #[test]
-    fn defrag_2_example() {
-        let solver = Solver::from_dense_format("2333133121414131402");
-        let defragged = solver.defrag_2();
-        print(&defragged);
-        let checksum = calculate_checksum(&defragged);
-        assert_eq!(checksum, 2858);
-    }
+    const EXAMPLE: &str = include_str!("day_09_example.txt");
 
     #[test]
     fn find_free_space_sections_finds_expected_sections() {
-        let solver = Solver::from_dense_format("2333133121414131402");
+        let solver = Solver::from_input(EXAMPLE).unwrap();
         let sections = find_free_space_sections(&solver.memory);
         let expected = vec![
             (2, 3),
@@ -295,9 +299,26 @@ mod tests {
         assert_eq!(sections, expected);
     }
 
+    #[test]
+    fn from_input_rejects_extra_lines() {
+        assert!(matches!(
+            Solver::from_input("2333133121414131402\n12"),
+            Err(Error::Parse { line: 2, .. })
+        ));
+    }
+
+    #[test]
+    fn disks_without_files_have_no_checksum() {
+        for disk_map in ["0", "01", "05"] {
+            let solver = Solver::from_input(disk_map).unwrap();
+            assert_eq!(solver.solve_part_1().unwrap(), Answer::from(0usize));
+            assert_eq!(solver.solve_part_2().unwrap(), Answer::from(0usize));
+        }
+    }
+
     #[test]
     fn find_memory_block_to_move_test() {
-        let solver = Solver::from_dense_format("2333133121414131402");
+        let solver = Solver::from_input(EXAMPLE).unwrap();
         let (start_index, length, id) = find_memory_block_to_move(&solver.memory, 7).unwrap();
         assert_eq!(start_index, 32);
         assert_eq!(length, 3);
@@ -0,0 +1,170 @@
+use crate::cli::Part;
+use crate::error::{Error, ParseContext, Result};
+use crate::input::numbered_lines;
+use crate::solver::Answer;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Confirmed answers for one year, stored in `answers/<year>.txt` as
+/// `<day> <part> <answer>` lines. Text is written in double quotes, so text
+/// made of digits isn't read back as a number, and rows of ASCII art are
+/// joined with `\n`.
+#[derive(Debug, Default, PartialEq)]
+pub struct KnownAnswers {
+    answers: BTreeMap<(u32, u32), Answer>,
+}
+
+impl KnownAnswers {
+    pub fn path(year: u32) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("answers")
+            .join(format!("{}.txt", year))
+    }
+
+    /// Loads the answers for a year, which are empty if none were recorded yet.
+    pub fn load(year: u32) -> Result<Self> {
+        match fs::read_to_string(Self::path(year)) {
+            Ok(contents) => Self::parse(&contents),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    pub fn save(&self, year: u32) -> Result<()> {
+        let path = Self::path(year);
+        if let Some(directory) = path.parent() {
+            fs::create_dir_all(directory)?;
+        }
+        fs::write(path, self.format(year))?;
+        Ok(())
+    }
+
+    pub fn parse(contents: &str) -> Result<Self> {
+        let mut answers = BTreeMap::new();
+        for (line_number, line) in numbered_lines(contents) {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (day, rest) = line.split_once(' ').at_line(line_number)?;
+            let day = day.parse().at_line(line_number)?;
+            let (part, answer) = parse_part_answer(rest, line_number)?;
+            answers.insert((day, part.number()), answer);
+        }
+        Ok(KnownAnswers { answers })
+    }
+
+    pub fn format(&self, year: u32) -> String {
+        let mut output = format!("# Confirmed answers for {}: <day> <part> <answer>\n", year);
+        for ((day, part), answer) in self.answers.iter() {
+            writeln!(output, "{} {} {}", day, part, encode(answer)).unwrap();
+        }
+        output
+    }
+
+    pub fn get(&self, day: u32, part: Part) -> Option<&Answer> {
+        self.answers.get(&(day, part.number()))
+    }
+
+    pub fn insert(&mut self, day: u32, part: Part, answer: Answer) {
+        self.answers.insert((day, part.number()), answer);
+    }
+}
+
+/// Parses the expected answers stored next to an input in a `.answers` file,
+/// as `<part> <answer>` lines. Parts without an answer are left out.
+pub fn parse_expected(contents: &str) -> Result<Vec<(Part, Answer)>> {
+    let mut expected = Vec::new();
+    for (line_number, line) in numbered_lines(contents) {
+        if line.trim().is_empty() || line.starts_with('#') {
+            continue;
+        }
+        expected.push(parse_part_answer(line, line_number)?);
+    }
+    Ok(expected)
+}
+
+fn parse_part_answer(line: &str, line_number: usize) -> Result<(Part, Answer)> {
+    let (part, answer) = match line.split_once(' ') {
+        Some(("1", answer)) => (Part::One, answer),
+        Some(("2", answer)) => (Part::Two, answer),
+        _ => return Err(Error::parse(line_number, "expected part 1 or 2")),
+    };
+    Ok((part, decode(answer)))
+}
+
+fn encode(answer: &Answer) -> String {
+    match answer {
+        Answer::Text(text) => format!("\"{}\"", text),
+        Answer::Art(rows) => rows.join("\\n"),
+        _ => answer.to_string(),
+    }
+}
+
+/// Reads an answer written by `encode`. Unquoted text that isn't a number
+/// or art is taken as text as well, for answers files written by hand.
+fn decode(value: &str) -> Answer {
+    if let Some(text) = value
+        .strip_prefix('"')
+        .and_then(|value| value.strip_suffix('"'))
+    {
+        Answer::Text(text.to_string())
+    } else if let Ok(value) = value.parse::<u64>() {
+        Answer::Unsigned(value)
+    } else if let Ok(value) = value.parse::<i64>() {
+        Answer::Signed(value)
+    } else if value.contains("\\n") {
+        Answer::Art(value.split("\\n").map(String::from).collect())
+    } else {
+        Answer::Text(value.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_every_kind_of_answer() {
+        let mut answers = KnownAnswers::default();
+        answers.insert(1, Part::One, Answer::from(42u64));
+        answers.insert(1, Part::Two, Answer::from(-7i64));
+        answers.insert(2, Part::One, Answer::from("a,b c"));
+        answers.insert(2, Part::Two, Answer::from("0123"));
+        answers.insert(
+            13,
+            Part::Two,
+            Answer::art(&[[true, false, true], [false, true, false]]),
+        );
+
+        let parsed = KnownAnswers::parse(&answers.format(2020)).unwrap();
+        assert_eq!(parsed, answers);
+        assert_eq!(parsed.get(13, Part::Two).unwrap().to_string(), "# #\n #");
+        assert_eq!(parsed.get(3, Part::One), None);
+    }
+
+    #[test]
+    fn parses_expected_answers() {
+        let expected = parse_expected("1 \"42\"\n2 #..\\n.#.\n").unwrap();
+        assert_eq!(
+            expected,
+            vec![
+                (Part::One, Answer::from("42")),
+                (Part::Two, Answer::Art(vec!["#..".into(), ".#.".into()]))
+            ]
+        );
+        assert_eq!(parse_expected("1 abc").unwrap()[0].1, Answer::from("abc"));
+        assert_eq!(parse_expected("1 42").unwrap()[0].1, Answer::from(42u64));
+    }
+
+    #[test]
+    fn reports_malformed_lines() {
+        let error = KnownAnswers::parse("# header\n1 3 42\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "malformed input on line 2: expected part 1 or 2"
+        );
+    }
+}
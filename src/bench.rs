@@ -0,0 +1,89 @@
+use crate::cli::BenchLimit;
+use std::time::Instant;
+
+/// Summary of repeated timings, in nanoseconds.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: u128,
+    pub median: u128,
+    pub mean: f64,
+    pub stddev: f64,
+}
+
+impl Stats {
+    pub fn from_samples(mut samples: Vec<u128>) -> Self {
+        assert!(!samples.is_empty(), "at least one sample is required");
+        samples.sort_unstable();
+
+        let runs = samples.len();
+        let middle = runs / 2;
+        let median = if runs.is_multiple_of(2) {
+            (samples[middle - 1] + samples[middle]) / 2
+        } else {
+            samples[middle]
+        };
+        let mean = samples.iter().sum::<u128>() as f64 / runs as f64;
+        let variance = samples
+            .iter()
+            .map(|&sample| (sample as f64 - mean).powi(2))
+            .sum::<f64>()
+            / runs as f64;
+
+        Stats {
+            runs,
+            min: samples[0],
+            median,
+            mean,
+            stddev: variance.sqrt(),
+        }
+    }
+}
+
+/// Runs `f` `warmup` times untimed, then samples it until `limit` is reached.
+/// At least one sample is always taken.
+pub fn measure<T, F: FnMut() -> T>(warmup: u32, limit: BenchLimit, mut f: F) -> Stats {
+    for _ in 0..warmup {
+        std::hint::black_box(f());
+    }
+
+    let mut samples = Vec::new();
+    let started = Instant::now();
+    loop {
+        let start = Instant::now();
+        std::hint::black_box(f());
+        samples.push(start.elapsed().as_nanos());
+
+        let done = match limit {
+            BenchLimit::Iterations(iterations) => samples.len() >= iterations as usize,
+            BenchLimit::Time(budget) => started.elapsed() >= budget,
+        };
+        if done {
+            break;
+        }
+    }
+    Stats::from_samples(samples)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_from_samples() {
+        let stats = Stats::from_samples(vec![4, 1, 3, 2]);
+        assert_eq!(stats.runs, 4);
+        assert_eq!(stats.min, 1);
+        assert_eq!(stats.median, 2);
+        assert_eq!(stats.mean, 2.5);
+        assert!((stats.stddev - 1.118).abs() < 0.001);
+    }
+
+    #[test]
+    fn measure_respects_iterations() {
+        let mut calls = 0;
+        let stats = measure(2, BenchLimit::Iterations(5), || calls += 1);
+        assert_eq!(stats.runs, 5);
+        assert_eq!(calls, 7);
+    }
+}
@@ -0,0 +1,819 @@
+use crate::input::InputSource;
+use std::ops::RangeInclusive;
+use std::path::PathBuf;
+use std::time::Duration;
+
+pub const USAGE: &str = "\
+Usage:
+  aoc run <year> [<days>] [--part 1|2] [--input <path>|-] [--timeout <ms>] [--memory] [--format <format>]
+  aoc run <year> <day> --inputs <dir> [--part 1|2] [--timeout <ms>] [--format <format>]
+  aoc run --all [<year>] [--part 1|2] [--jobs <n>] [--timeout <ms>] [--memory] [--format <format>]
+  aoc bench <year> [<days>] [--part 1|2] [--iterations <n>|--time <ms>] [--warmup <n>] [--format <format>]
+  aoc new <year> <day>
+  aoc watch <year> <day>
+  aoc readme [--hide-answers]
+  aoc verify [<year> [<days>]] [--record] [--timeout <ms>] [--format <format>]
+  aoc perf diff [<commit>] [--threshold <percent>]
+  aoc list
+  aoc help
+
+<days> is a single day (17), an inclusive range (1..=13) or an exclusive
+range (1..14). When omitted, every implemented day of the year is run.
+
+--input reads the puzzle input from a file, or from stdin when given '-',
+instead of src/<year>/day_XX.txt. It requires a single day.
+
+--inputs runs a single day on every file in <dir>, such as the inputs of
+different people, and checks the answers of each against the <part> <answer>
+lines of a matching <file>.answers file when there is one.
+
+--timeout gives up on the parse or a part of a day after <ms> milliseconds
+and reports it as TIMEOUT. A day that panics is reported as PANIC. Either way
+the remaining days still run.
+
+--memory counts the heap allocations, bytes allocated and peak live bytes of
+the parse and each part, and shows them next to the timings.
+
+run --all runs every implemented day, of one year or all of them, on <n>
+threads (one per CPU by default) and prints a summary table.
+
+bench runs the parse and each part <n> times, or for <ms> milliseconds
+(1000 by default), after <n> warmup runs (1 by default), and reports
+min/median/mean/stddev in nanoseconds. Every bench run is appended to
+perf-history.txt along with the current commit and time.
+
+perf diff compares the median of each phase in the latest bench run with its
+most recent earlier measurement, or the latest one made at <commit>, and flags
+the ones that got slower by more than <percent> (10 by default).
+
+new creates src/<year>/day_XX.rs from src/template.rs, along with an empty
+input and example, and never overwrites an existing day.
+
+watch reruns the tests, examples and solver of a day through cargo whenever
+its source, input or examples change, and shows how the answers changed.
+
+readme runs every implemented day and writes a timing table per year to the
+marked section of README.md, leaving the rest of the file untouched.
+
+verify checks answers against the confirmed ones in answers/<year>.txt, for
+every year when none is given. --record saves the answers of days that have
+none recorded yet.
+
+--format prints the results of run, bench and verify as text (the default),
+json (an array of objects) or csv (a header and one row per record).";
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn number(self) -> u32 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BenchLimit {
+    Iterations(u32),
+    Time(Duration),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Run {
+        year: u32,
+        days: Option<RangeInclusive<u32>>,
+        part: Option<Part>,
+        input: InputSource,
+        timeout: Option<Duration>,
+        memory: bool,
+        format: Format,
+    },
+    RunInputs {
+        year: u32,
+        day: u32,
+        directory: PathBuf,
+        part: Option<Part>,
+        timeout: Option<Duration>,
+        format: Format,
+    },
+    RunAll {
+        year: Option<u32>,
+        part: Option<Part>,
+        jobs: Option<usize>,
+        timeout: Option<Duration>,
+        memory: bool,
+        format: Format,
+    },
+    Bench {
+        year: u32,
+        days: Option<RangeInclusive<u32>>,
+        part: Option<Part>,
+        limit: BenchLimit,
+        warmup: u32,
+        format: Format,
+    },
+    New {
+        year: u32,
+        day: u32,
+    },
+    Watch {
+        year: u32,
+        day: u32,
+    },
+    Readme {
+        hide_answers: bool,
+    },
+    Verify {
+        year: Option<u32>,
+        days: Option<RangeInclusive<u32>>,
+        record: bool,
+        timeout: Option<Duration>,
+        format: Format,
+    },
+    PerfDiff {
+        baseline: Option<String>,
+        threshold: u32,
+    },
+    List,
+    Help,
+}
+
+impl Command {
+    pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Self, String> {
+        let mut args = args.into_iter();
+        let command = match args.next() {
+            Some(command) => command,
+            None => return Ok(Command::Help),
+        };
+
+        match command.as_str() {
+            "run" => parse_run(args),
+            "bench" => parse_bench(args),
+            "new" => parse_new(args),
+            "watch" => parse_watch(args),
+            "readme" => parse_readme(args),
+            "verify" => parse_verify(args),
+            "perf" => parse_perf(args),
+            "list" => expect_no_more(args).map(|_| Command::List),
+            "help" | "-h" | "--help" => Ok(Command::Help),
+            _ => Err(format!("Unknown command '{}'", command)),
+        }
+    }
+}
+
+/// Positional arguments, `--name value` options and `--name` flags, in the order given.
+struct Arguments {
+    positional: Vec<String>,
+    options: Vec<(&'static str, String)>,
+    flags: Vec<&'static str>,
+}
+
+impl Arguments {
+    /// `options` and `flags` pair each long name with its short alias.
+    fn parse<I: Iterator<Item = String>>(
+        mut args: I,
+        options: &[(&'static str, &'static str)],
+        flags: &[(&'static str, &'static str)],
+    ) -> Result<Self, String> {
+        let mut positional = Vec::new();
+        let mut values = Vec::new();
+        let mut set_flags = Vec::new();
+
+        while let Some(arg) = args.next() {
+            if !arg.starts_with('-') || arg == "-" {
+                positional.push(arg);
+                continue;
+            }
+
+            let (name, value) = match arg.split_once('=') {
+                Some((name, value)) => (name, Some(value.to_string())),
+                None => (arg.as_str(), None),
+            };
+            if let Some((long, _)) = flags
+                .iter()
+                .find(|(long, short)| name == *long || name == *short)
+            {
+                if value.is_some() {
+                    return Err(format!("Flag '{}' does not take a value", name));
+                }
+                set_flags.push(*long);
+                continue;
+            }
+            let long = options
+                .iter()
+                .find(|(long, short)| name == *long || name == *short)
+                .map(|(long, _)| *long)
+                .ok_or_else(|| format!("Unknown option '{}'", arg))?;
+            let value = match value {
+                Some(value) => value,
+                None => args
+                    .next()
+                    .ok_or_else(|| format!("Missing value for '{}'", arg))?,
+            };
+            values.push((long, value));
+        }
+
+        Ok(Arguments {
+            positional,
+            options: values,
+            flags: set_flags,
+        })
+    }
+
+    fn flag(&self, name: &str) -> bool {
+        self.flags.contains(&name)
+    }
+
+    /// The last value given for an option.
+    fn value(&self, name: &str) -> Option<&str> {
+        self.options
+            .iter()
+            .rev()
+            .find(|(option, _)| *option == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Parses `<year> [<days>]`.
+    fn selection(&self) -> Result<(u32, Option<RangeInclusive<u32>>), String> {
+        let mut positional = self.positional.iter();
+        let year = match positional.next() {
+            Some(year) => parse_number(year, "year")?,
+            None => return Err("Missing <year> argument".to_string()),
+        };
+        let days = match positional.next() {
+            Some(days) => Some(parse_days(days)?),
+            None => None,
+        };
+        expect_no_more(positional)?;
+        Ok((year, days))
+    }
+
+    fn part(&self) -> Result<Option<Part>, String> {
+        self.value("--part").map(parse_part).transpose()
+    }
+
+    fn timeout(&self) -> Result<Option<Duration>, String> {
+        match self.value("--timeout") {
+            Some(timeout) => match parse_number(timeout, "timeout")? {
+                0 => Err("--timeout must be at least 1".to_string()),
+                timeout => Ok(Some(Duration::from_millis(timeout as u64))),
+            },
+            None => Ok(None),
+        }
+    }
+
+    fn format(&self) -> Result<Format, String> {
+        match self.value("--format") {
+            None | Some("text") => Ok(Format::Text),
+            Some("json") => Ok(Format::Json),
+            Some("csv") => Ok(Format::Csv),
+            Some(value) => Err(format!(
+                "Invalid format '{}', expected text, json or csv",
+                value
+            )),
+        }
+    }
+}
+
+fn parse_run<I: Iterator<Item = String>>(args: I) -> Result<Command, String> {
+    let args = Arguments::parse(
+        args,
+        &[
+            ("--part", "-p"),
+            ("--input", "-i"),
+            ("--inputs", ""),
+            ("--jobs", "-j"),
+            ("--timeout", ""),
+            ("--format", "-f"),
+        ],
+        &[("--all", "-a"), ("--memory", "-m")],
+    )?;
+    let part = args.part()?;
+    let timeout = args.timeout()?;
+    let memory = args.flag("--memory");
+    let format = args.format()?;
+
+    if args.flag("--all") {
+        let mut positional = args.positional.iter();
+        let year = match positional.next() {
+            Some(year) => Some(parse_number(year, "year")?),
+            None => None,
+        };
+        expect_no_more(positional)?;
+        for option in ["--input", "--inputs"] {
+            if args.value(option).is_some() {
+                return Err(format!("{} cannot be used with --all", option));
+            }
+        }
+        let jobs = match args.value("--jobs") {
+            Some(jobs) => match parse_number(jobs, "jobs")? {
+                0 => return Err("--jobs must be at least 1".to_string()),
+                jobs => Some(jobs as usize),
+            },
+            None => None,
+        };
+        return Ok(Command::RunAll {
+            year,
+            part,
+            jobs,
+            timeout,
+            memory,
+            format,
+        });
+    }
+    if args.value("--jobs").is_some() {
+        return Err("--jobs requires --all".to_string());
+    }
+
+    let (year, days) = args.selection()?;
+    let is_single_day = matches!(&days, Some(days) if days.start() == days.end());
+
+    if let Some(directory) = args.value("--inputs") {
+        let day = match &days {
+            Some(days) if is_single_day => *days.start(),
+            _ => return Err("--inputs requires a single day".to_string()),
+        };
+        if args.value("--input").is_some() {
+            return Err("--input cannot be used with --inputs".to_string());
+        }
+        if memory {
+            return Err("--memory cannot be used with --inputs".to_string());
+        }
+        return Ok(Command::RunInputs {
+            year,
+            day,
+            directory: PathBuf::from(directory),
+            part,
+            timeout,
+            format,
+        });
+    }
+
+    let input = match args.value("--input") {
+        Some(value) => InputSource::parse(value),
+        None => InputSource::Default,
+    };
+
+    if input != InputSource::Default && !is_single_day {
+        return Err("--input requires a single day".to_string());
+    }
+
+    Ok(Command::Run {
+        year,
+        days,
+        part,
+        input,
+        timeout,
+        memory,
+        format,
+    })
+}
+
+fn parse_bench<I: Iterator<Item = String>>(args: I) -> Result<Command, String> {
+    let args = Arguments::parse(
+        args,
+        &[
+            ("--part", "-p"),
+            ("--iterations", "-n"),
+            ("--time", "-t"),
+            ("--warmup", "-w"),
+            ("--format", "-f"),
+        ],
+        &[],
+    )?;
+    let (year, days) = args.selection()?;
+    let part = args.part()?;
+
+    let limit = match (args.value("--iterations"), args.value("--time")) {
+        (Some(_), Some(_)) => return Err("--iterations and --time are exclusive".to_string()),
+        (Some(iterations), None) => BenchLimit::Iterations(parse_number(iterations, "iterations")?),
+        (None, Some(time)) => {
+            BenchLimit::Time(Duration::from_millis(parse_number(time, "time")? as u64))
+        }
+        (None, None) => BenchLimit::Time(Duration::from_millis(1000)),
+    };
+    if limit == BenchLimit::Iterations(0) {
+        return Err("--iterations must be at least 1".to_string());
+    }
+    let warmup = match args.value("--warmup") {
+        Some(warmup) => parse_number(warmup, "warmup")?,
+        None => 1,
+    };
+
+    Ok(Command::Bench {
+        year,
+        days,
+        part,
+        limit,
+        warmup,
+        format: args.format()?,
+    })
+}
+
+fn parse_new<I: Iterator<Item = String>>(args: I) -> Result<Command, String> {
+    let (year, day) = parse_year_and_day(args)?;
+    Ok(Command::New { year, day })
+}
+
+fn parse_watch<I: Iterator<Item = String>>(args: I) -> Result<Command, String> {
+    let (year, day) = parse_year_and_day(args)?;
+    Ok(Command::Watch { year, day })
+}
+
+/// Parses `<year> <day>` naming a single day, which may not exist yet.
+fn parse_year_and_day<I: Iterator<Item = String>>(args: I) -> Result<(u32, u32), String> {
+    let args = Arguments::parse(args, &[], &[])?;
+    let mut positional = args.positional.iter();
+    let year = match positional.next() {
+        Some(year) if year.len() == 4 => parse_number(year, "year")?,
+        Some(year) => return Err(format!("Invalid year '{}'", year)),
+        None => return Err("Missing <year> argument".to_string()),
+    };
+    let day = match positional.next() {
+        Some(day) => parse_number(day, "day")?,
+        None => return Err("Missing <day> argument".to_string()),
+    };
+    expect_no_more(positional)?;
+    if !(1..=25).contains(&day) {
+        return Err(format!("Invalid day '{}', expected 1 to 25", day));
+    }
+    Ok((year, day))
+}
+
+fn parse_readme<I: Iterator<Item = String>>(args: I) -> Result<Command, String> {
+    let args = Arguments::parse(args, &[], &[("--hide-answers", "")])?;
+    expect_no_more(args.positional.iter())?;
+    Ok(Command::Readme {
+        hide_answers: args.flag("--hide-answers"),
+    })
+}
+
+fn parse_verify<I: Iterator<Item = String>>(args: I) -> Result<Command, String> {
+    let args = Arguments::parse(
+        args,
+        &[("--timeout", ""), ("--format", "-f")],
+        &[("--record", "")],
+    )?;
+    let (year, days) = if args.positional.is_empty() {
+        (None, None)
+    } else {
+        let (year, days) = args.selection()?;
+        (Some(year), days)
+    };
+    Ok(Command::Verify {
+        year,
+        days,
+        record: args.flag("--record"),
+        timeout: args.timeout()?,
+        format: args.format()?,
+    })
+}
+
+fn parse_perf<I: Iterator<Item = String>>(mut args: I) -> Result<Command, String> {
+    match args.next().as_deref() {
+        Some("diff") => {}
+        Some(subcommand) => return Err(format!("Unknown perf command '{}'", subcommand)),
+        None => return Err("Missing perf command, expected 'diff'".to_string()),
+    }
+    let args = Arguments::parse(args, &[("--threshold", "-t")], &[])?;
+    let mut positional = args.positional.iter();
+    let baseline = positional.next().cloned();
+    expect_no_more(positional)?;
+    let threshold = match args.value("--threshold") {
+        Some(threshold) => parse_number(threshold, "threshold")?,
+        None => 10,
+    };
+    Ok(Command::PerfDiff {
+        baseline,
+        threshold,
+    })
+}
+
+fn parse_part(value: &str) -> Result<Part, String> {
+    match value {
+        "1" => Ok(Part::One),
+        "2" => Ok(Part::Two),
+        _ => Err(format!("Invalid part '{}', expected 1 or 2", value)),
+    }
+}
+
+fn parse_days(value: &str) -> Result<RangeInclusive<u32>, String> {
+    let range = if let Some((start, end)) = value.split_once("..=") {
+        parse_number(start, "day")?..=parse_number(end, "day")?
+    } else if let Some((start, end)) = value.split_once("..") {
+        let end = parse_number(end, "day")?;
+        if end == 0 {
+            return Err(format!("Invalid day range '{}'", value));
+        }
+        parse_number(start, "day")?..=end - 1
+    } else {
+        let day = parse_number(value, "day")?;
+        day..=day
+    };
+
+    if range.is_empty() {
+        return Err(format!("Invalid day range '{}'", value));
+    }
+    Ok(range)
+}
+
+fn parse_number(value: &str, name: &str) -> Result<u32, String> {
+    value
+        .parse()
+        .map_err(|_| format!("Invalid {} '{}'", name, value))
+}
+
+fn expect_no_more<T: AsRef<str>, I: Iterator<Item = T>>(mut args: I) -> Result<(), String> {
+    match args.next() {
+        Some(arg) => Err(format!("Unexpected argument '{}'", arg.as_ref())),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Command, String> {
+        Command::parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn parses_single_day() {
+        let actual = parse(&["run", "2020", "17"]).unwrap();
+        let expected = Command::Run {
+            year: 2020,
+            days: Some(17..=17),
+            part: None,
+            input: InputSource::Default,
+            timeout: None,
+            memory: false,
+            format: Format::Text,
+        };
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn parses_day_ranges_and_part() {
+        let actual = parse(&["run", "2021", "1..=13", "--part", "2"]).unwrap();
+        let expected = Command::Run {
+            year: 2021,
+            days: Some(1..=13),
+            part: Some(Part::Two),
+            input: InputSource::Default,
+            timeout: None,
+            memory: false,
+            format: Format::Text,
+        };
+        assert_eq!(actual, expected);
+
+        let actual = parse(&["run", "--part=1", "2021", "1..14"]).unwrap();
+        let expected = Command::Run {
+            year: 2021,
+            days: Some(1..=13),
+            part: Some(Part::One),
+            input: InputSource::Default,
+            timeout: None,
+            memory: false,
+            format: Format::Text,
+        };
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn parses_input_source() {
+        let actual = parse(&["run", "2020", "1", "--input", "-"]).unwrap();
+        let expected = Command::Run {
+            year: 2020,
+            days: Some(1..=1),
+            part: None,
+            input: InputSource::Stdin,
+            timeout: None,
+            memory: false,
+            format: Format::Text,
+        };
+        assert_eq!(actual, expected);
+
+        let actual = parse(&["run", "2020", "1", "-i", "example.txt"]).unwrap();
+        let expected = Command::Run {
+            year: 2020,
+            days: Some(1..=1),
+            part: None,
+            input: InputSource::File("example.txt".into()),
+            timeout: None,
+            memory: false,
+            format: Format::Text,
+        };
+        assert_eq!(actual, expected);
+
+        let actual = parse(&["run", "2021", "12", "--inputs", "inputs/", "-p", "2"]).unwrap();
+        let expected = Command::RunInputs {
+            year: 2021,
+            day: 12,
+            directory: "inputs/".into(),
+            part: Some(Part::Two),
+            timeout: None,
+            format: Format::Text,
+        };
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn parses_run_all() {
+        let actual = parse(&["run", "--all"]).unwrap();
+        let expected = Command::RunAll {
+            year: None,
+            part: None,
+            jobs: None,
+            timeout: None,
+            memory: false,
+            format: Format::Text,
+        };
+        assert_eq!(actual, expected);
+
+        let actual = parse(&["run", "-a", "2020", "-j", "4", "--part", "2"]).unwrap();
+        let expected = Command::RunAll {
+            year: Some(2020),
+            part: Some(Part::Two),
+            jobs: Some(4),
+            timeout: None,
+            memory: false,
+            format: Format::Text,
+        };
+        assert_eq!(actual, expected);
+
+        let actual = parse(&["run", "--all", "--timeout", "500", "--memory"]).unwrap();
+        let expected = Command::RunAll {
+            year: None,
+            part: None,
+            jobs: None,
+            timeout: Some(Duration::from_millis(500)),
+            memory: true,
+            format: Format::Text,
+        };
+        assert_eq!(actual, expected);
+
+        assert!(parse(&["run", "--all", "2020", "1"]).is_err());
+        assert!(parse(&["run", "--all", "--timeout", "0"]).is_err());
+        assert!(parse(&["run", "2020", "--jobs", "2"]).is_err());
+    }
+
+    #[test]
+    fn parses_bench_limits() {
+        let actual = parse(&["bench", "2020", "5"]).unwrap();
+        let expected = Command::Bench {
+            year: 2020,
+            days: Some(5..=5),
+            part: None,
+            limit: BenchLimit::Time(Duration::from_millis(1000)),
+            warmup: 1,
+            format: Format::Text,
+        };
+        assert_eq!(actual, expected);
+
+        let actual = parse(&["bench", "2021", "-n", "50", "--warmup=3", "-p", "1"]).unwrap();
+        let expected = Command::Bench {
+            year: 2021,
+            days: None,
+            part: Some(Part::One),
+            limit: BenchLimit::Iterations(50),
+            warmup: 3,
+            format: Format::Text,
+        };
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn parses_new_day() {
+        let actual = parse(&["new", "2025", "3"]).unwrap();
+        assert_eq!(actual, Command::New { year: 2025, day: 3 });
+
+        assert!(parse(&["new", "2025"]).is_err());
+        assert!(parse(&["new", "25", "3"]).is_err());
+        assert!(parse(&["new", "2025", "26"]).is_err());
+
+        let actual = parse(&["watch", "2024", "5"]).unwrap();
+        assert_eq!(actual, Command::Watch { year: 2024, day: 5 });
+    }
+
+    #[test]
+    fn parses_readme_flags() {
+        let actual = parse(&["readme"]).unwrap();
+        assert_eq!(
+            actual,
+            Command::Readme {
+                hide_answers: false
+            }
+        );
+
+        let actual = parse(&["readme", "--hide-answers"]).unwrap();
+        assert_eq!(actual, Command::Readme { hide_answers: true });
+    }
+
+    #[test]
+    fn parses_verify_selection() {
+        let actual = parse(&["verify"]).unwrap();
+        let expected = Command::Verify {
+            year: None,
+            days: None,
+            record: false,
+            timeout: None,
+            format: Format::Text,
+        };
+        assert_eq!(actual, expected);
+
+        let actual = parse(&["verify", "2021", "1..=5", "--record"]).unwrap();
+        let expected = Command::Verify {
+            year: Some(2021),
+            days: Some(1..=5),
+            record: true,
+            timeout: None,
+            format: Format::Text,
+        };
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn parses_output_format() {
+        let actual = parse(&["run", "2020", "1", "--format", "json"]).unwrap();
+        let expected = Command::Run {
+            year: 2020,
+            days: Some(1..=1),
+            part: None,
+            input: InputSource::Default,
+            timeout: None,
+            memory: false,
+            format: Format::Json,
+        };
+        assert_eq!(actual, expected);
+
+        let actual = parse(&["verify", "-f", "csv"]).unwrap();
+        let expected = Command::Verify {
+            year: None,
+            days: None,
+            record: false,
+            timeout: None,
+            format: Format::Csv,
+        };
+        assert_eq!(actual, expected);
+
+        assert!(matches!(
+            parse(&["bench", "2020", "--format=json"]),
+            Ok(Command::Bench {
+                format: Format::Json,
+                ..
+            })
+        ));
+        assert!(parse(&["run", "2020", "--format", "xml"]).is_err());
+        assert!(parse(&["readme", "--format", "json"]).is_err());
+    }
+
+    #[test]
+    fn parses_perf_diff() {
+        let actual = parse(&["perf", "diff"]).unwrap();
+        let expected = Command::PerfDiff {
+            baseline: None,
+            threshold: 10,
+        };
+        assert_eq!(actual, expected);
+
+        let actual = parse(&["perf", "diff", "abc1234", "--threshold", "25"]).unwrap();
+        let expected = Command::PerfDiff {
+            baseline: Some("abc1234".to_string()),
+            threshold: 25,
+        };
+        assert_eq!(actual, expected);
+
+        assert!(parse(&["perf"]).is_err());
+        assert!(parse(&["perf", "show"]).is_err());
+    }
+
+    #[test]
+    fn rejects_invalid_arguments() {
+        assert!(parse(&["run"]).is_err());
+        assert!(parse(&["run", "2020", "5..=1"]).is_err());
+        assert!(parse(&["run", "2020", "1", "--part", "3"]).is_err());
+        assert!(parse(&["run", "2020", "1", "2"]).is_err());
+        assert!(parse(&["run", "2020", "--input", "example.txt"]).is_err());
+        assert!(parse(&["run", "2021", "1..=2", "--inputs", "inputs"]).is_err());
+        assert!(parse(&["run", "--all", "--inputs", "inputs"]).is_err());
+        assert!(parse(&["bench", "2020", "--iterations", "5", "--time", "10"]).is_err());
+        assert!(parse(&["bench", "2020", "--input", "example.txt"]).is_err());
+        assert!(parse(&["readme", "--hide-answers=yes"]).is_err());
+        assert!(parse(&["list", "2020"]).is_err());
+        assert!(parse(&["fly"]).is_err());
+    }
+}
@@ -0,0 +1,89 @@
+use std::fmt::{self, Display};
+use std::io;
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    /// The puzzle input is malformed. Line numbers start at 1.
+    Parse {
+        line: usize,
+        message: String,
+    },
+    NoSolution,
+    EmptyInput,
+    /// A phase of the solver ran past its deadline.
+    Timeout,
+    /// The solver panicked, with the panic's message.
+    Panic(String),
+}
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+impl Error {
+    pub fn parse<M: Into<String>>(line: usize, message: M) -> Self {
+        Error::Parse {
+            line,
+            message: message.into(),
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Io(error) => write!(f, "I/O error: {}", error),
+            Error::Parse { line, message } => {
+                write!(f, "malformed input on line {}: {}", line, message)
+            }
+            Error::NoSolution => write!(f, "no solution exists"),
+            Error::EmptyInput => write!(f, "the input is empty"),
+            Error::Timeout => write!(f, "TIMEOUT"),
+            Error::Panic(message) => write!(f, "PANIC: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Error::Io(error)
+    }
+}
+
+/// Attaches the offending line number to a failed parse step.
+pub trait ParseContext<T> {
+    fn at_line(self, line: usize) -> Result<T>;
+}
+
+impl<T, E: Display> ParseContext<T> for Result<T, E> {
+    fn at_line(self, line: usize) -> Result<T> {
+        self.map_err(|error| Error::parse(line, error.to_string()))
+    }
+}
+
+impl<T> ParseContext<T> for Option<T> {
+    fn at_line(self, line: usize) -> Result<T> {
+        self.ok_or_else(|| Error::parse(line, "unexpected format"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn at_line_reports_line_and_cause() {
+        let error = "x".parse::<u32>().at_line(3).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "malformed input on line 3: invalid digit found in string"
+        );
+
+        let error = None::<u32>.at_line(7).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "malformed input on line 7: unexpected format"
+        );
+    }
+}
@@ -0,0 +1,31 @@
+use crate::answers::parse_expected;
+use crate::input::normalize;
+use crate::registry::get_solver;
+use crate::runner::solve_part;
+
+/// Solves an example input through the registry and compares every part that
+/// has an expected answer.
+fn check(year: u32, day: u32, input: &str, answers: &str) {
+    let expected = parse_expected(answers).unwrap();
+    assert!(!expected.is_empty(), "the example has no expected answers");
+
+    let create_solver = get_solver(year, day).unwrap();
+    let solver = create_solver(&normalize(input).unwrap()).unwrap();
+    for (part, answer) in expected {
+        let actual = solve_part(solver.as_ref(), part).unwrap();
+        assert_eq!(actual, answer, "part {}", part.number());
+    }
+}
+
+/// Generates a test for an example input and the `.answers` file next to it.
+macro_rules! example_test {
+    ($name:ident, $year:expr, $day:expr, $input:expr, $answers:expr) => {
+        #[test]
+        fn $name() {
+            check($year, $day, include_str!($input), include_str!($answers));
+        }
+    };
+}
+
+// Generated by build.rs from the `src/<year>/day_XX_example[_N].txt` files
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
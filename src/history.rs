@@ -0,0 +1,246 @@
+use crate::bench::Stats;
+use crate::error::{Error, ParseContext, Result};
+use crate::input::numbered_lines;
+use std::fmt::Write as _;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const HEADER: &str =
+    "# <timestamp_ms> <commit> <year> <day> <phase> <runs> <min_ns> <median_ns> <mean_ns>\n";
+
+/// One benchmarked phase of a day, as recorded in the history file.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Entry {
+    /// Milliseconds since the Unix epoch, shared by every entry of a bench run.
+    pub timestamp: u64,
+    pub commit: String,
+    pub year: u32,
+    pub day: u32,
+    pub phase: String,
+    pub runs: usize,
+    pub min: u128,
+    pub median: u128,
+    pub mean: f64,
+}
+
+impl Entry {
+    pub fn new(run: &(u64, String), year: u32, day: u32, phase: String, stats: &Stats) -> Self {
+        Entry {
+            timestamp: run.0,
+            commit: run.1.clone(),
+            year,
+            day,
+            phase,
+            runs: stats.runs,
+            min: stats.min,
+            median: stats.median,
+            mean: stats.mean,
+        }
+    }
+
+    fn is_same_phase(&self, other: &Entry) -> bool {
+        self.year == other.year && self.day == other.day && self.phase == other.phase
+    }
+}
+
+/// A phase whose median changed between a baseline and the latest run.
+#[derive(Debug, PartialEq)]
+pub struct Change<'a> {
+    pub baseline: &'a Entry,
+    pub latest: &'a Entry,
+    /// The change of the median in percent, positive when it got slower.
+    pub percent: f64,
+}
+
+/// Every bench run so far, in `perf-history.txt` with one line per phase.
+#[derive(Debug, Default, PartialEq)]
+pub struct History {
+    pub entries: Vec<Entry>,
+}
+
+impl History {
+    pub fn path() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("perf-history.txt")
+    }
+
+    /// Loads the history, which is empty if nothing was benchmarked yet.
+    pub fn load() -> Result<Self> {
+        match fs::read_to_string(Self::path()) {
+            Ok(contents) => Self::parse(&contents),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    pub fn parse(contents: &str) -> Result<Self> {
+        let mut entries = Vec::new();
+        for (line_number, line) in numbered_lines(contents) {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let fields: Vec<&str> = line.split(' ').collect();
+            if fields.len() != 9 {
+                return Err(Error::parse(line_number, "expected 9 fields"));
+            }
+            entries.push(Entry {
+                timestamp: fields[0].parse().at_line(line_number)?,
+                commit: fields[1].to_string(),
+                year: fields[2].parse().at_line(line_number)?,
+                day: fields[3].parse().at_line(line_number)?,
+                phase: fields[4].to_string(),
+                runs: fields[5].parse().at_line(line_number)?,
+                min: fields[6].parse().at_line(line_number)?,
+                median: fields[7].parse().at_line(line_number)?,
+                mean: fields[8].parse().at_line(line_number)?,
+            });
+        }
+        Ok(History { entries })
+    }
+
+    /// Appends entries to the history file, creating it when needed.
+    pub fn append(entries: &[Entry]) -> Result<()> {
+        let path = Self::path();
+        let is_new = !path.exists();
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        let mut output = String::new();
+        if is_new {
+            output.push_str(HEADER);
+        }
+        for entry in entries {
+            output.push_str(&format_entry(entry));
+        }
+        file.write_all(output.as_bytes())?;
+        Ok(())
+    }
+
+    /// Compares every phase of the latest run with the most recent earlier
+    /// measurement of it, optionally only those made at a commit starting with
+    /// `baseline`. Phases without a baseline are left out.
+    pub fn diff(&self, baseline: Option<&str>) -> Vec<Change<'_>> {
+        let latest = match self.entries.last() {
+            Some(entry) => (entry.timestamp, &entry.commit),
+            None => return Vec::new(),
+        };
+        let (previous, latest_run): (Vec<&Entry>, Vec<&Entry>) = self
+            .entries
+            .iter()
+            .partition(|entry| (entry.timestamp, &entry.commit) != latest);
+
+        latest_run
+            .into_iter()
+            .filter_map(|latest| {
+                let baseline = previous.iter().rev().find(|entry| {
+                    entry.is_same_phase(latest)
+                        && baseline.is_none_or(|commit| entry.commit.starts_with(commit))
+                })?;
+                let percent = (latest.median as f64 / baseline.median.max(1) as f64 - 1.0) * 100.0;
+                Some(Change {
+                    baseline,
+                    latest,
+                    percent,
+                })
+            })
+            .collect()
+    }
+}
+
+fn format_entry(entry: &Entry) -> String {
+    let mut line = String::new();
+    writeln!(
+        line,
+        "{} {} {} {} {} {} {} {} {:.0}",
+        entry.timestamp,
+        entry.commit,
+        entry.year,
+        entry.day,
+        entry.phase,
+        entry.runs,
+        entry.min,
+        entry.median,
+        entry.mean
+    )
+    .unwrap();
+    line
+}
+
+/// The timestamp and commit that identify a bench run. The commit is the
+/// short hash of `HEAD`, marked `-dirty` when there are uncommitted changes.
+pub fn current_run() -> (u64, String) {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |time| time.as_millis() as u64);
+    let git = |args: &[&str]| {
+        let output = Command::new("git")
+            .args(args)
+            .current_dir(env!("CARGO_MANIFEST_DIR"))
+            .output()
+            .ok()?;
+        if !output.status.success() {
+            return None;
+        }
+        Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+    let commit = match git(&["rev-parse", "--short", "HEAD"]) {
+        Some(commit) if git(&["status", "--porcelain"]).is_some_and(|s| !s.is_empty()) => {
+            format!("{}-dirty", commit)
+        }
+        Some(commit) => commit,
+        None => "unknown".to_string(),
+    };
+    (timestamp, commit)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(timestamp: u64, commit: &str, day: u32, median: u128) -> Entry {
+        Entry {
+            timestamp,
+            commit: commit.to_string(),
+            year: 2024,
+            day,
+            phase: "part_1".to_string(),
+            runs: 10,
+            min: median,
+            median,
+            mean: median as f64,
+        }
+    }
+
+    #[test]
+    fn round_trips_entries() {
+        let entries = vec![
+            entry(1, "abc1234", 5, 1000),
+            entry(2, "def5678-dirty", 9, 20),
+        ];
+        let contents: String =
+            HEADER.to_string() + &entries.iter().map(format_entry).collect::<String>();
+        assert_eq!(History::parse(&contents).unwrap(), History { entries });
+    }
+
+    #[test]
+    fn diffs_latest_run_against_earlier_ones() {
+        let history = History {
+            entries: vec![
+                entry(1, "aaa", 5, 1000),
+                entry(1, "aaa", 9, 100),
+                entry(2, "bbb", 5, 800),
+                entry(3, "ccc", 5, 1200),
+                entry(3, "ccc", 6, 50),
+            ],
+        };
+
+        let changes = history.diff(None);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].baseline.commit, "bbb");
+        assert_eq!(changes[0].percent.round(), 50.0);
+
+        let changes = history.diff(Some("a"));
+        assert_eq!(changes[0].baseline.commit, "aaa");
+        assert_eq!(changes[0].percent.round(), 20.0);
+    }
+}
@@ -0,0 +1,116 @@
+use crate::error::{Error, Result};
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum InputSource {
+    /// The committed puzzle input at `src/<year>/day_XX.txt`
+    Default,
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    pub fn parse(value: &str) -> Self {
+        match value {
+            "-" => InputSource::Stdin,
+            _ => InputSource::File(PathBuf::from(value)),
+        }
+    }
+}
+
+pub fn default_input_path(year: u32, day: u32) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("src")
+        .join(year.to_string())
+        .join(format!("day_{:02}.txt", day))
+}
+
+/// Reads and normalizes the input of a day, printing a warning for anything
+/// suspicious in it.
+pub fn read_input(year: u32, day: u32, source: &InputSource) -> Result<String> {
+    let (raw, name) = match source {
+        InputSource::Default => {
+            let path = default_input_path(year, day);
+            (read_file(&path)?, path.display().to_string())
+        }
+        InputSource::File(path) => (read_file(path)?, path.display().to_string()),
+        InputSource::Stdin => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input)?;
+            (input, "stdin".to_string())
+        }
+    };
+    let input = normalize(&raw)?;
+    for warning in check(&input) {
+        eprintln!("warning: {}: {}", name, warning);
+    }
+    Ok(input)
+}
+
+/// Turns `\r\n` line endings into `\n` and removes the trailing line breaks,
+/// so every solver sees its input the same way. Fails on an input that is
+/// empty or only whitespace.
+pub fn normalize(raw: &str) -> Result<String> {
+    let input = raw.replace("\r\n", "\n");
+    let input = input.trim_end_matches('\n');
+    if input.trim().is_empty() {
+        return Err(Error::EmptyInput);
+    }
+    Ok(input.to_string())
+}
+
+/// Describes characters that puzzle inputs don't normally contain, with the
+/// first line they appear on.
+pub fn check(input: &str) -> Vec<String> {
+    let first_line = |matches: fn(char) -> bool| {
+        numbered_lines(input)
+            .find(|(_, line)| line.chars().any(matches))
+            .map(|(line_number, _)| line_number)
+    };
+    let mut warnings = Vec::new();
+    if let Some(line_number) = first_line(|c| c == '\t') {
+        warnings.push(format!("tab character on line {}", line_number));
+    }
+    if let Some(line_number) = first_line(|c| !c.is_ascii()) {
+        warnings.push(format!("non-ASCII character on line {}", line_number));
+    }
+    warnings
+}
+
+fn read_file(path: &Path) -> Result<String> {
+    fs::read_to_string(path).map_err(|error| {
+        io::Error::new(error.kind(), format!("{}: {}", path.display(), error)).into()
+    })
+}
+
+/// Iterates over the lines of the input along with their line numbers, starting at 1.
+pub fn numbered_lines(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_line_endings() {
+        assert_eq!(normalize("1\r\n2\r\n\r\n").unwrap(), "1\n2");
+        assert_eq!(normalize("1\n\n2").unwrap(), "1\n\n2");
+        assert_eq!(normalize("  #\n").unwrap(), "  #");
+        assert!(matches!(normalize(" \n\n"), Err(Error::EmptyInput)));
+    }
+
+    #[test]
+    fn warns_about_unusual_characters() {
+        assert!(check("1 2\n3 4").is_empty());
+        assert_eq!(
+            check("a\nb\tc\nd\u{e9}"),
+            vec!["tab character on line 2", "non-ASCII character on line 3"]
+        );
+    }
+}
@@ -1,140 +1,680 @@
-#![feature(extract_if)]
-
 extern crate nom;
 
+mod answers;
+mod bench;
+mod cli;
+mod error;
+#[cfg(test)]
+mod examples;
+mod history;
+mod input;
+mod memory;
+mod pool;
+mod readme;
+mod registry;
+mod report;
+mod runner;
+mod scaffold;
 mod solver;
+mod verify;
+mod watch;
 
-use solver::AoCSolver;
-use std::time::Instant;
+use bench::Stats;
+use cli::{BenchLimit, Command, Format, Part};
+use colored::Colorize;
+use error::Error;
+use history::History;
+use input::InputSource;
+use memory::Usage;
+use registry::get_solver;
+use report::{Record, Value};
+use runner::{DayRun, PartRun};
+use solver::Answer;
+use std::fs;
+use std::path::Path;
+use std::process;
+use std::time::{Duration, Instant};
 
 #[path = "util/mod.rs"]
 mod util;
-#[path = "2020/mod.rs"]
-mod y_2020;
-#[path = "2021/mod.rs"]
-mod y_2021;
-#[path = "2024/mod.rs"]
-mod y_2024;
+
+#[global_allocator]
+static ALLOCATOR: memory::CountingAllocator = memory::CountingAllocator;
 
 fn main() {
-    solve(2024, 9);
-    // for year in 2021..=2021 {
-    //     for day in 1..=8 {
-    //         solve(year, day);
-    //     }
-    // }
+    let command = match Command::parse(std::env::args().skip(1)) {
+        Ok(command) => command,
+        Err(error) => {
+            eprintln!("error: {}", error);
+            eprintln!();
+            eprintln!("{}", cli::USAGE);
+            process::exit(2);
+        }
+    };
+
+    if let Err(error) = execute(command) {
+        eprintln!("error: {}", error);
+        process::exit(1);
+    }
 }
 
-fn solve(year: u32, day: u32) {
-    let total_start = Instant::now();
+fn execute(command: Command) -> Result<(), String> {
+    match command {
+        Command::Run {
+            year,
+            days,
+            part,
+            input,
+            timeout,
+            memory,
+            format,
+        } => {
+            let days = registry::resolve_days(year, days)?;
+            if memory {
+                memory::enable();
+            }
+            if format == Format::Text {
+                return for_each_day(days, |day| solve(year, day, part, &input, timeout));
+            }
+            let mut records = Vec::new();
+            let result = for_each_day(days, |day| {
+                let run = runner::run_from(year, day, part, &input, timeout);
+                records.extend(run_records(year, day, &run, memory));
+                match run_failures(year, day, &run).into_iter().next() {
+                    Some(failure) => Err(failure),
+                    None => Ok(()),
+                }
+            });
+            report::print(format, &records);
+            result
+        }
+        Command::RunInputs {
+            year,
+            day,
+            directory,
+            part,
+            timeout,
+            format,
+        } => verify::run_inputs(year, day, &directory, part, timeout, format),
+        Command::RunAll {
+            year,
+            part,
+            jobs,
+            timeout,
+            memory,
+            format,
+        } => {
+            if memory {
+                memory::enable();
+            }
+            run_all(year, part, jobs, timeout, memory, format)
+        }
+        Command::Bench {
+            year,
+            days,
+            part,
+            limit,
+            warmup,
+            format,
+        } => {
+            let days = registry::resolve_days(year, days)?;
+            let run = history::current_run();
+            let mut entries = Vec::new();
+            let mut records = Vec::new();
+            let result = for_each_day(days, |day| {
+                let phases = run_bench(year, day, part, limit, warmup)?;
+                entries.extend(phases.iter().map(|(part, stats)| {
+                    history::Entry::new(&run, year, day, phase_name(*part), stats)
+                }));
+                match format {
+                    Format::Text => print_bench(year, day, &phases),
+                    _ => records.extend(phases.iter().map(|(part, stats)| {
+                        vec![
+                            ("year", Value::from(year)),
+                            ("day", Value::from(day)),
+                            ("phase", Value::from(phase_name(*part))),
+                            ("runs", Value::from(stats.runs)),
+                            ("min_ns", Value::from(stats.min)),
+                            ("median_ns", Value::from(stats.median)),
+                            ("mean_ns", Value::from(stats.mean)),
+                            ("stddev_ns", Value::from(stats.stddev)),
+                        ]
+                    })),
+                }
+                Ok(())
+            });
+            report::print(format, &records);
+            if !entries.is_empty() {
+                let path = History::path();
+                History::append(&entries)
+                    .map_err(|error| format!("{}: {}", path.display(), error))?;
+                if format == Format::Text {
+                    println!("Appended to {} as {}", path.display(), run.1);
+                }
+            }
+            result
+        }
+        Command::New { year, day } => {
+            let src_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+            let created = scaffold::new_day(&src_dir, year, day)
+                .map_err(|error| format!("Could not create {} day {}: {}", year, day, error))?;
+            for path in created {
+                println!("Created {}", path.display());
+            }
+            Ok(())
+        }
+        Command::Watch { year, day } => watch::watch(year, day),
+        Command::Readme { hide_answers } => write_readme(hide_answers),
+        Command::Verify {
+            year,
+            days,
+            record,
+            timeout,
+            format,
+        } => verify::verify(year, days, record, timeout, format),
+        Command::PerfDiff {
+            baseline,
+            threshold,
+        } => perf_diff(baseline.as_deref(), threshold),
+        Command::List => {
+            for year in registry::years() {
+                let days = registry::days(year)
+                    .iter()
+                    .map(|day| day.to_string())
+                    .collect::<Vec<String>>()
+                    .join(" ");
+                println!("{}: {}", year, days);
+            }
+            Ok(())
+        }
+        Command::Help => {
+            println!("{}", cli::USAGE);
+            Ok(())
+        }
+    }
+}
 
-    let input_parse_start = Instant::now();
-    let solver = get_solver(year, day).unwrap()();
-    let input_parse_time = &input_parse_start.elapsed().as_micros();
+/// Runs every day, reporting failures as they happen instead of stopping at the
+/// first one. The failure of a single day is returned as is.
+fn for_each_day<F>(days: Vec<u32>, mut f: F) -> Result<(), String>
+where
+    F: FnMut(u32) -> Result<(), String>,
+{
+    if let [day] = days[..] {
+        return f(day);
+    }
+    let mut failures = 0;
+    for day in days {
+        if let Err(error) = f(day) {
+            eprintln!("error: {}", error);
+            failures += 1;
+        }
+    }
+    if failures > 0 {
+        return Err(format!("{} day(s) could not be solved", failures));
+    }
+    Ok(())
+}
 
-    let part_1_start = Instant::now();
-    let part_1_result = solver.solve_part_1();
-    let part_1_time = &part_1_start.elapsed().as_micros();
+fn solve(
+    year: u32,
+    day: u32,
+    part: Option<Part>,
+    input: &InputSource,
+    timeout: Option<Duration>,
+) -> Result<(), String> {
+    let run = runner::run_from(year, day, part, input, timeout)
+        .map_err(|error| format!("{} day {} failed: {}", year, day, error))?;
 
-    let part_2_start = Instant::now();
-    let part_2_result = solver.solve_part_2();
-    let part_2_time = &part_2_start.elapsed().as_micros();
+    println!("===== {}: Day {} =====", year, day);
+    for part_run in run.parts.iter() {
+        match &part_run.answer {
+            Ok(answer) => print_answer(part_run.part.number(), answer),
+            Err(error) => println!("  - Part {}: error: {}", part_run.part.number(), error),
+        }
+    }
+
+    println!("  - Timings");
+    println!("    - Reading: {} μs", run.read_time.as_micros());
+    println!("    - Parsing: {} μs", run.parse_time.as_micros());
+    for part_run in run.parts.iter() {
+        println!(
+            "    - Part {}: {} μs",
+            part_run.part.number(),
+            part_run.time.as_micros()
+        );
+    }
+    println!("    - Total: {} μs", run.total_time.as_micros());
 
-    let total_time = &total_start.elapsed().as_micros();
+    if let Some(usage) = run.parse_memory {
+        println!("  - Memory");
+        println!("    - Parsing: {}", usage_line(&usage));
+        for part_run in run.parts.iter() {
+            if let Some(usage) = part_run.memory {
+                println!(
+                    "    - Part {}: {}",
+                    part_run.part.number(),
+                    usage_line(&usage)
+                );
+            }
+        }
+    }
+
+    match run.first_error() {
+        Some(error) => Err(format!("{} day {} failed: {}", year, day, error)),
+        None => Ok(()),
+    }
+}
 
+fn usage_line(usage: &Usage) -> String {
+    format!(
+        "{} allocations, {} allocated, {} peak",
+        usage.allocations,
+        memory::format_bytes(usage.bytes),
+        memory::format_bytes(usage.peak)
+    )
+}
+
+/// One record per part of a run, or a single one when the day failed to run.
+/// The heap usage of each phase is added when `memory` was counted.
+fn run_records(year: u32, day: u32, run: &Result<DayRun, String>, memory: bool) -> Vec<Record> {
+    let record = |part_run: Option<&PartRun>| {
+        let error = match run {
+            Ok(_) => part_run
+                .and_then(|part_run| part_run.answer.as_ref().err())
+                .map(|error| error.to_string()),
+            Err(error) => Some(error.clone()),
+        };
+        let run = run.as_ref().ok();
+        let mut record = vec![
+            ("year", Value::from(year)),
+            ("day", Value::from(day)),
+            (
+                "part",
+                Value::from(part_run.map(|part_run| part_run.part.number())),
+            ),
+            (
+                "answer",
+                Value::from(part_run.and_then(|part_run| part_run.answer.as_ref().ok())),
+            ),
+            ("error", Value::from(error)),
+            (
+                "read_ns",
+                Value::from(run.map(|run| run.read_time.as_nanos())),
+            ),
+            (
+                "parse_ns",
+                Value::from(run.map(|run| run.parse_time.as_nanos())),
+            ),
+            (
+                "time_ns",
+                Value::from(part_run.map(|part_run| part_run.time.as_nanos())),
+            ),
+            (
+                "total_ns",
+                Value::from(run.map(|run| run.total_time.as_nanos())),
+            ),
+        ];
+        if memory {
+            let parse = run.and_then(|run| run.parse_memory);
+            let part = part_run.and_then(|part_run| part_run.memory);
+            record.extend(vec![
+                (
+                    "parse_allocations",
+                    Value::from(parse.map(|usage| usage.allocations)),
+                ),
+                ("parse_bytes", Value::from(parse.map(|usage| usage.bytes))),
+                (
+                    "parse_peak_bytes",
+                    Value::from(parse.map(|usage| usage.peak)),
+                ),
+                (
+                    "allocations",
+                    Value::from(part.map(|usage| usage.allocations)),
+                ),
+                ("bytes", Value::from(part.map(|usage| usage.bytes))),
+                ("peak_bytes", Value::from(part.map(|usage| usage.peak))),
+            ]);
+        }
+        record
+    };
+    match run {
+        Ok(run) => run
+            .parts
+            .iter()
+            .map(|part_run| record(Some(part_run)))
+            .collect(),
+        Err(_) => vec![record(None)],
+    }
+}
+
+/// Why a day, or any of its parts, could not be solved.
+fn run_failures(year: u32, day: u32, run: &Result<DayRun, String>) -> Vec<String> {
+    match run {
+        Ok(run) => run
+            .parts
+            .iter()
+            .filter_map(|part_run| {
+                let error = part_run.answer.as_ref().err()?;
+                Some(format!(
+                    "{} day {} part {} failed: {}",
+                    year,
+                    day,
+                    part_run.part.number(),
+                    error
+                ))
+            })
+            .collect(),
+        Err(error) => vec![format!("{} day {} failed: {}", year, day, error)],
+    }
+}
+
+fn run_all(
+    year: Option<u32>,
+    part: Option<Part>,
+    jobs: Option<usize>,
+    timeout: Option<Duration>,
+    memory: bool,
+    format: Format,
+) -> Result<(), String> {
+    let days: Vec<(u32, u32)> = registry::all()
+        .filter(|&(y, _)| year.is_none_or(|year| y == year))
+        .collect();
+    if days.is_empty() {
+        return Err(match year {
+            Some(year) => format!("No solvers implemented for year {}", year),
+            None => "No solvers implemented".to_string(),
+        });
+    }
+
+    let threads = jobs.unwrap_or_else(pool::default_threads);
+    let start = Instant::now();
+    let runs = pool::run_parallel(days, threads, |&(year, day)| {
+        (year, day, runner::run_committed(year, day, part, timeout))
+    });
+    let wall_time = start.elapsed();
+
+    let failures: Vec<String> = runs
+        .iter()
+        .flat_map(|(year, day, run)| run_failures(*year, *day, run))
+        .collect();
+    if format != Format::Text {
+        let records: Vec<Record> = runs
+            .iter()
+            .flat_map(|(year, day, run)| run_records(*year, *day, run, memory))
+            .collect();
+        report::print(format, &records);
+    } else {
+        print_summary(&runs, wall_time, threads, memory);
+    }
+
+    for failure in failures.iter() {
+        eprintln!("error: {}", failure);
+    }
+    if !failures.is_empty() {
+        return Err(format!("{} failure(s)", failures.len()));
+    }
+    Ok(())
+}
+
+/// Prints a table of every run, followed by any art answers in full. The heap
+/// usage of each day is summed over its phases when `memory` was counted.
+fn print_summary(
+    runs: &[(u32, u32, Result<DayRun, String>)],
+    wall_time: Duration,
+    threads: usize,
+    memory: bool,
+) {
+    let mut header = vec![
+        "Year", "Day", "Answer 1", "Answer 2", "Parsing", "Part 1", "Part 2", "Total",
+    ];
+    if memory {
+        header.extend(vec!["Allocations", "Allocated", "Peak"]);
+    }
+    let mut rows = vec![header
+        .iter()
+        .map(|header| header.to_string())
+        .collect::<Vec<String>>()];
+    let mut art = Vec::new();
+    let mut summed_time = Duration::default();
+
+    for (year, day, run) in runs.iter() {
+        let mut row = vec![year.to_string(), day.to_string()];
+        match run {
+            Ok(run) => {
+                summed_time += run.total_time;
+                let parts = [run.part(Part::One), run.part(Part::Two)];
+                for part_run in parts.iter() {
+                    row.push(match part_run.map(|part_run| &part_run.answer) {
+                        Some(Ok(answer @ Answer::Art(_))) => {
+                            art.push((*year, *day, part_run.unwrap().part, answer));
+                            "[art]".to_string()
+                        }
+                        Some(Ok(answer)) => answer.to_string(),
+                        Some(Err(Error::Timeout)) => "TIMEOUT".to_string(),
+                        Some(Err(Error::Panic(_))) => "PANIC".to_string(),
+                        Some(Err(_)) => "error".to_string(),
+                        None => String::new(),
+                    });
+                }
+                row.push(format!("{} μs", run.parse_time.as_micros()));
+                for part_run in parts.iter() {
+                    row.push(part_run.map_or(String::new(), |part_run| {
+                        format!("{} μs", part_run.time.as_micros())
+                    }));
+                }
+                row.push(format!("{} μs", run.total_time.as_micros()));
+                if memory {
+                    let usage = run
+                        .parts
+                        .iter()
+                        .filter_map(|part_run| part_run.memory)
+                        .fold(run.parse_memory.unwrap_or_default(), Usage::combine);
+                    row.push(usage.allocations.to_string());
+                    row.push(memory::format_bytes(usage.bytes));
+                    row.push(memory::format_bytes(usage.peak));
+                }
+            }
+            Err(_) => row.extend(vec!["error".to_string(); if memory { 9 } else { 6 }]),
+        }
+        rows.push(row);
+    }
+
+    print_table(&rows, &[2, 3]);
+    for (year, day, part, answer) in art {
+        println!();
+        println!("===== {}: Day {} =====", year, day);
+        print_answer(part.number(), answer);
+    }
+    println!();
+    println!(
+        "Wall-clock time: {} ms, summed per-day time: {} ms on {} thread(s)",
+        wall_time.as_millis(),
+        summed_time.as_millis(),
+        threads
+    );
+}
+
+/// Prints rows as aligned columns. The `left` columns are left-aligned and
+/// the others, such as numbers and timings, right-aligned.
+fn print_table(rows: &[Vec<String>], left: &[usize]) {
+    let columns = rows[0].len();
+    let widths: Vec<usize> = (0..columns)
+        .map(|column| {
+            rows.iter()
+                .map(|row| row[column].chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    for row in rows {
+        let cells: Vec<String> = row
+            .iter()
+            .zip(widths.iter())
+            .enumerate()
+            .map(|(column, (cell, &width))| {
+                if left.contains(&column) {
+                    format!("{:<width$}", cell, width = width)
+                } else {
+                    format!("{:>width$}", cell, width = width)
+                }
+            })
+            .collect();
+        println!("{}", cells.join("  ").trim_end());
+    }
+}
+
+fn perf_diff(baseline: Option<&str>, threshold: u32) -> Result<(), String> {
+    let path = History::path();
+    let history = History::load().map_err(|error| format!("{}: {}", path.display(), error))?;
+    let changes = history.diff(baseline);
+    if changes.is_empty() {
+        return Err(format!(
+            "No earlier measurements to compare with in {}",
+            path.display()
+        ));
+    }
+
+    let mut rows = vec![vec![
+        "Year".to_string(),
+        "Day".to_string(),
+        "Phase".to_string(),
+        "Baseline".to_string(),
+        "Latest".to_string(),
+        "Change".to_string(),
+    ]];
+    let mut regressions = 0;
+    for change in changes.iter() {
+        let mut row = vec![
+            change.latest.year.to_string(),
+            change.latest.day.to_string(),
+            change.latest.phase.clone(),
+            format!("{} ns ({})", change.baseline.median, change.baseline.commit),
+            format!("{} ns", change.latest.median),
+            format!("{:+.1}%", change.percent),
+        ];
+        if change.percent > threshold as f64 {
+            row.push("REGRESSION".red().bold().to_string());
+            regressions += 1;
+        }
+        rows.push(row);
+    }
+    // Pad the rows without a flag so every row has the same columns
+    let columns = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+    for row in rows.iter_mut() {
+        row.resize(columns, String::new());
+    }
+    print_table(&rows, &[2, 6]);
+
+    if regressions > 0 {
+        return Err(format!(
+            "{} phase(s) slowed down by more than {}%",
+            regressions, threshold
+        ));
+    }
+    Ok(())
+}
+
+fn write_readme(hide_answers: bool) -> Result<(), String> {
+    let mut years = Vec::new();
+    let mut failures = 0;
+    for year in registry::years() {
+        let mut days = Vec::new();
+        for day in registry::days(year) {
+            eprintln!("Running {} day {}", year, day);
+            let run = runner::run_committed(year, day, None, None).and_then(|run| {
+                match run.first_error() {
+                    Some(error) => Err(error.to_string()),
+                    None => Ok(run),
+                }
+            });
+            if let Err(error) = &run {
+                eprintln!("error: {} day {} failed: {}", year, day, error);
+                failures += 1;
+            }
+            days.push((day, run));
+        }
+        years.push((year, days));
+    }
+
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("README.md");
+    let readme = fs::read_to_string(&path).unwrap_or_default();
+    let readme = readme::replace_section(&readme, &readme::render(&years, hide_answers));
+    fs::write(&path, readme).map_err(|error| format!("{}: {}", path.display(), error))?;
+    println!("Wrote timings to {}", path.display());
+
+    if failures > 0 {
+        return Err(format!("{} day(s) could not be solved", failures));
+    }
+    Ok(())
+}
+
+fn print_answer(part: u32, answer: &Answer) {
+    match answer {
+        Answer::Art(rows) => {
+            println!("  - Part {}:", part);
+            for row in rows {
+                println!("      {}", row);
+            }
+        }
+        _ => println!("  - Part {}: {}", part, answer),
+    }
+}
+
+/// Measures the parse and the selected parts of a day, after solving it once
+/// so failures are reported instead of benchmarked.
+fn run_bench(
+    year: u32,
+    day: u32,
+    part: Option<Part>,
+    limit: BenchLimit,
+    warmup: u32,
+) -> Result<Vec<(Option<Part>, Stats)>, String> {
+    let create_solver = get_solver(year, day)?;
+    let failed = |error| format!("{} day {} failed: {}", year, day, error);
+    let input = input::read_input(year, day, &InputSource::Default).map_err(failed)?;
+    let run = runner::run_day(create_solver, &input, part, None).map_err(failed)?;
+    if let Some(error) = run.first_error() {
+        return Err(format!("{} day {} failed: {}", year, day, error));
+    }
+    let solver = create_solver(&input).map_err(failed)?;
+
+    let mut phases = vec![(
+        None,
+        bench::measure(warmup, limit, || create_solver(&input)),
+    )];
+    for part in runner::selected_parts(part) {
+        phases.push((
+            Some(part),
+            bench::measure(warmup, limit, || runner::solve_part(solver.as_ref(), part)),
+        ));
+    }
+    Ok(phases)
+}
+
+/// The name of a benchmarked phase in machine-readable output.
+fn phase_name(part: Option<Part>) -> String {
+    match part {
+        Some(part) => format!("part_{}", part.number()),
+        None => "parse".to_string(),
+    }
+}
+
+fn print_bench(year: u32, day: u32, phases: &[(Option<Part>, Stats)]) {
     println!("===== {}: Day {} =====", year, day);
-    println!("  - Part 1: {}", part_1_result);
-    println!("  - Part 2: {}", part_2_result);
-    println!("  - Timings");
-    println!("    - Parsing: {} μs", input_parse_time);
-    println!("    - Part 1: {} μs", part_1_time);
-    println!("    - Part 2: {} μs", part_2_time);
-    println!("    - Total: {} μs", total_time);
-
-    // println!("| {} | {} μs | {} μs | {} μs |", day, input_parse_time, part_1_time, part_2_time);
-}
-
-fn get_solver(year: u32, day: u32) -> Result<fn() -> Box<dyn AoCSolver>, String> {
-    match year {
-        2020 => match day {
-            01 => Ok(|| Box::new(y_2020::day_01::Solver::create())),
-            02 => Ok(|| Box::new(y_2020::day_02::Solver::create())),
-            03 => Ok(|| Box::new(y_2020::day_03::Solver::create())),
-            04 => Ok(|| Box::new(y_2020::day_04::Solver::create())),
-            05 => Ok(|| Box::new(y_2020::day_05::Solver::create())),
-            06 => Ok(|| Box::new(y_2020::day_06::Solver::create())),
-            07 => Ok(|| Box::new(y_2020::day_07::Solver::create())),
-            08 => Ok(|| Box::new(y_2020::day_08::Solver::create())),
-            09 => Ok(|| Box::new(y_2020::day_09::Solver::create())),
-            10 => Ok(|| Box::new(y_2020::day_10::Solver::create())),
-            11 => Ok(|| Box::new(y_2020::day_11::Solver::create())),
-            12 => Ok(|| Box::new(y_2020::day_12::Solver::create())),
-            13 => Ok(|| Box::new(y_2020::day_13::Solver::create())),
-            14 => Ok(|| Box::new(y_2020::day_14::Solver::create())),
-            15 => Ok(|| Box::new(y_2020::day_15::Solver::create())),
-            16 => Ok(|| Box::new(y_2020::day_16::Solver::create())),
-            17 => Ok(|| Box::new(y_2020::day_17::Solver::create())),
-            18 => Ok(|| Box::new(y_2020::day_18::Solver::create())),
-            19 => Ok(|| Box::new(y_2020::day_19::Solver::create())),
-            20 => Ok(|| Box::new(y_2020::day_20::Solver::create())),
-            21 => Ok(|| Box::new(y_2020::day_21::Solver::create())),
-            22 => Ok(|| Box::new(y_2020::day_22::Solver::create())),
-            23 => Ok(|| Box::new(y_2020::day_23::Solver::create())),
-            24 => Ok(|| Box::new(y_2020::day_24::Solver::create())),
-            25 => Ok(|| Box::new(y_2020::day_25::Solver::create())),
-            _ => Err(format!(
-                "No solvers implemented for year {} day {}",
-                year, day
-            )),
-        },
-        2021 => match day {
-            01 => Ok(|| Box::new(y_2021::day_01::Solver::create())),
-            02 => Ok(|| Box::new(y_2021::day_02::Solver::create())),
-            03 => Ok(|| Box::new(y_2021::day_03::Solver::create())),
-            04 => Ok(|| Box::new(y_2021::day_04::Solver::create())),
-            05 => Ok(|| Box::new(y_2021::day_05::Solver::create())),
-            06 => Ok(|| Box::new(y_2021::day_06::Solver::create())),
-            07 => Ok(|| Box::new(y_2021::day_07::Solver::create())),
-            08 => Ok(|| Box::new(y_2021::day_08::Solver::create())),
-            09 => Ok(|| Box::new(y_2021::day_09::Solver::create())),
-            10 => Ok(|| Box::new(y_2021::day_10::Solver::create())),
-            11 => Ok(|| Box::new(y_2021::day_11::Solver::create())),
-            12 => Ok(|| Box::new(y_2021::day_12::Solver::create())),
-            13 => Ok(|| Box::new(y_2021::day_13::Solver::create())),
-            // 14 => Ok(|| Box::new(y_2021::day_14::Solver::create())),
-            // 15 => Ok(|| Box::new(y_2021::day_15::Solver::create())),
-            // 16 => Ok(|| Box::new(y_2021::day_16::Solver::create())),
-            // 17 => Ok(|| Box::new(y_2021::day_17::Solver::create())),
-            // 18 => Ok(|| Box::new(y_2021::day_18::Solver::create())),
-            // 19 => Ok(|| Box::new(y_2021::day_19::Solver::create())),
-            // 20 => Ok(|| Box::new(y_2021::day_20::Solver::create())),
-            // 21 => Ok(|| Box::new(y_2021::day_21::Solver::create())),
-            // 22 => Ok(|| Box::new(y_2021::day_22::Solver::create())),
-            // 23 => Ok(|| Box::new(y_2021::day_23::Solver::create())),
-            // 24 => Ok(|| Box::new(y_2021::day_24::Solver::create())),
-            // 25 => Ok(|| Box::new(y_2021::day_25::Solver::create())),
-            _ => Err(format!(
-                "No solvers implemented for year {} day {}",
-                year, day
-            )),
-        },
-        2024 => match day {
-            01 => Ok(|| Box::new(y_2024::day_01::Solver::create())),
-            02 => Ok(|| Box::new(y_2024::day_02::Solver::create())),
-            03 => Ok(|| Box::new(y_2024::day_03::Solver::create())),
-            04 => Ok(|| Box::new(y_2024::day_04::Solver::create())),
-            05 => Ok(|| Box::new(y_2024::day_05::Solver::create())),
-            06 => Ok(|| Box::new(y_2024::day_06::Solver::create())),
-            08 => Ok(|| Box::new(y_2024::day_08::Solver::create())),
-            09 => Ok(|| Box::new(y_2024::day_09::Solver::create())),
-            _ => Err(format!(
-                "No solvers implemented for year {} day {}",
-                year, day
-            )),
-        },
-        _ => Err(format!(
-            "No solvers implemented for year {} day {}",
-            year, day
-        )),
+    println!(
+        "  {:<8} {:>8} {:>14} {:>14} {:>14} {:>14}",
+        "", "runs", "min (ns)", "median (ns)", "mean (ns)", "stddev (ns)"
+    );
+    for (part, stats) in phases {
+        let phase = match part {
+            Some(part) => format!("Part {}", part.number()),
+            None => "Parsing".to_string(),
+        };
+        println!(
+            "  {:<8} {:>8} {:>14} {:>14} {:>14.0} {:>14.0}",
+            phase, stats.runs, stats.min, stats.median, stats.mean, stats.stddev
+        );
     }
 }
@@ -0,0 +1,177 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Wraps the system allocator to count the allocations of each thread once
+/// `enable` has been called. Until then it only forwards to `System`.
+pub struct CountingAllocator;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+#[derive(Clone, Copy)]
+struct Counters {
+    allocations: u64,
+    allocated: u64,
+    /// Bytes freed on another thread than the one that allocated them can
+    /// make this go below zero.
+    live: i64,
+    peak: i64,
+}
+
+thread_local! {
+    static COUNTERS: Cell<Counters> = const {
+        Cell::new(Counters {
+            allocations: 0,
+            allocated: 0,
+            live: 0,
+            peak: 0,
+        })
+    };
+}
+
+fn record(allocated: usize, freed: usize) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+    let _ = COUNTERS.try_with(|counters| {
+        let mut current = counters.get();
+        if allocated > 0 {
+            current.allocations += 1;
+            current.allocated += allocated as u64;
+        }
+        current.live += allocated as i64 - freed as i64;
+        current.peak = current.peak.max(current.live);
+        counters.set(current);
+    });
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let pointer = System.alloc(layout);
+        if !pointer.is_null() {
+            record(layout.size(), 0);
+        }
+        pointer
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let pointer = System.alloc_zeroed(layout);
+        if !pointer.is_null() {
+            record(layout.size(), 0);
+        }
+        pointer
+    }
+
+    unsafe fn realloc(&self, pointer: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let pointer = System.realloc(pointer, layout, new_size);
+        if !pointer.is_null() {
+            record(new_size, layout.size());
+        }
+        pointer
+    }
+
+    unsafe fn dealloc(&self, pointer: *mut u8, layout: Layout) {
+        System.dealloc(pointer, layout);
+        record(0, layout.size());
+    }
+}
+
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Heap usage of one phase of a solver.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Usage {
+    /// Calls to allocate or grow memory.
+    pub allocations: u64,
+    /// Bytes requested across all of those calls.
+    pub bytes: u64,
+    /// The most bytes live at once, above what was live when the phase began.
+    pub peak: u64,
+}
+
+impl Usage {
+    /// The usage of two phases run one after the other, with the larger peak.
+    pub fn combine(self, other: Usage) -> Usage {
+        Usage {
+            allocations: self.allocations + other.allocations,
+            bytes: self.bytes + other.bytes,
+            peak: self.peak.max(other.peak),
+        }
+    }
+}
+
+/// Runs `f`, counting what it allocates on the current thread. The usage is
+/// only given when counting was enabled.
+pub fn measure<T, F: FnOnce() -> T>(f: F) -> (T, Option<Usage>) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return (f(), None);
+    }
+
+    let start = COUNTERS.with(|counters| {
+        let start = counters.get();
+        counters.set(Counters {
+            peak: start.live,
+            ..start
+        });
+        start
+    });
+    let result = f();
+    let end = COUNTERS.with(|counters| {
+        let end = counters.get();
+        counters.set(Counters {
+            peak: end.peak.max(start.peak),
+            ..end
+        });
+        end
+    });
+
+    let usage = Usage {
+        allocations: end.allocations - start.allocations,
+        bytes: end.allocated - start.allocated,
+        peak: (end.peak - start.live).max(0) as u64,
+    };
+    (result, Some(usage))
+}
+
+/// Formats a number of bytes with a binary unit, such as `1.5 MiB`.
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", value, UNITS[unit])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_allocations_of_a_phase() {
+        enable();
+        let (_, usage) = measure(|| {
+            let small = vec![0u8; 100];
+            drop(small);
+            vec![0u8; 1000]
+        });
+        let usage = usage.unwrap();
+        assert_eq!(usage.allocations, 2);
+        assert_eq!(usage.bytes, 1100);
+        assert_eq!(usage.peak, 1000);
+    }
+
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+}
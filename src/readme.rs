@@ -0,0 +1,128 @@
+use crate::cli::Part;
+use crate::runner::DayRun;
+use crate::solver::Answer;
+use std::fmt::Write;
+use std::time::Duration;
+
+const START_MARKER: &str = "<!-- timings:start -->";
+const END_MARKER: &str = "<!-- timings:end -->";
+
+/// The outcome of running one day, or why it could not be run.
+pub type DayReport = (u32, Result<DayRun, String>);
+
+/// Renders a Markdown table per year of answers and timings.
+pub fn render(years: &[(u32, Vec<DayReport>)], hide_answers: bool) -> String {
+    let mut output = String::new();
+
+    for (year, days) in years {
+        writeln!(output, "### {}", year).unwrap();
+        writeln!(output).unwrap();
+        if hide_answers {
+            writeln!(output, "| Day | Parsing | Part 1 | Part 2 | Total |").unwrap();
+            writeln!(output, "|----:|--------:|-------:|-------:|------:|").unwrap();
+        } else {
+            writeln!(
+                output,
+                "| Day | Answer 1 | Answer 2 | Parsing | Part 1 | Part 2 | Total |"
+            )
+            .unwrap();
+            writeln!(
+                output,
+                "|----:|---------:|---------:|--------:|-------:|-------:|------:|"
+            )
+            .unwrap();
+        }
+
+        let mut totals = [Duration::default(); 4];
+        for (day, run) in days {
+            let mut cells = vec![day.to_string()];
+            match run {
+                Ok(run) => {
+                    let part_1 = run.part(Part::One);
+                    let part_2 = run.part(Part::Two);
+                    if !hide_answers {
+                        cells.push(answer_cell(part_1.map(|part| &part.answer)));
+                        cells.push(answer_cell(part_2.map(|part| &part.answer)));
+                    }
+                    let times = [
+                        run.parse_time,
+                        part_1.map(|part| part.time).unwrap_or_default(),
+                        part_2.map(|part| part.time).unwrap_or_default(),
+                        run.total_time,
+                    ];
+                    for (total, time) in totals.iter_mut().zip(times.iter()) {
+                        *total += *time;
+                    }
+                    cells.extend(times.iter().map(|&time| time_cell(time)));
+                }
+                Err(_) => {
+                    let columns = if hide_answers { 4 } else { 6 };
+                    cells.extend((0..columns).map(|_| "error".to_string()));
+                }
+            }
+            writeln!(output, "| {} |", cells.join(" | ")).unwrap();
+        }
+
+        let mut cells = vec!["**Total**".to_string()];
+        if !hide_answers {
+            cells.extend(vec![String::new(); 2]);
+        }
+        cells.extend(
+            totals
+                .iter()
+                .map(|&time| format!("**{}**", time_cell(time))),
+        );
+        writeln!(output, "| {} |", cells.join(" | ")).unwrap();
+        writeln!(output).unwrap();
+    }
+
+    output
+}
+
+fn answer_cell(answer: Option<&crate::error::Result<Answer>>) -> String {
+    match answer {
+        Some(Ok(Answer::Art(rows))) => format!("<pre>{}</pre>", rows.join("<br>")),
+        Some(Ok(answer)) => answer.to_string().replace('|', "\\|"),
+        Some(Err(_)) => "error".to_string(),
+        None => String::new(),
+    }
+}
+
+fn time_cell(time: Duration) -> String {
+    format!("{} μs", time.as_micros())
+}
+
+/// Replaces the text between the timing markers, or appends a new marked
+/// section when the markers aren't there yet.
+pub fn replace_section(readme: &str, section: &str) -> String {
+    let marked = format!("{}\n\n{}{}", START_MARKER, section, END_MARKER);
+    match (readme.find(START_MARKER), readme.find(END_MARKER)) {
+        (Some(start), Some(end)) if start < end => format!(
+            "{}{}{}",
+            &readme[..start],
+            marked,
+            &readme[end + END_MARKER.len()..]
+        ),
+        _ => format!("{}\n\n## Timings\n\n{}\n", readme.trim_end(), marked),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn replace_section_appends_then_replaces() {
+        let first = replace_section("# advent-of-code\n", "one\n\n");
+        assert_eq!(
+            first,
+            "# advent-of-code\n\n## Timings\n\n<!-- timings:start -->\n\none\n\n<!-- timings:end -->\n"
+        );
+
+        let second = replace_section(&(first + "\nFooter\n"), "two\n\n");
+        assert_eq!(
+            second,
+            "# advent-of-code\n\n## Timings\n\n<!-- timings:start -->\n\ntwo\n\n<!-- timings:end -->\n\nFooter\n"
+        );
+    }
+}
@@ -0,0 +1,71 @@
+use crate::error::Result;
+use crate::solver::AoCSolver;
+use std::ops::RangeInclusive;
+
+pub type CreateSolver = fn(&str) -> Result<Box<dyn AoCSolver>>;
+
+pub struct SolverEntry {
+    pub year: u32,
+    pub day: u32,
+    pub create: CreateSolver,
+}
+
+// Generated by build.rs from the `src/<year>/day_XX.rs` files
+include!(concat!(env!("OUT_DIR"), "/solvers.rs"));
+
+pub fn get_solver(year: u32, day: u32) -> std::result::Result<CreateSolver, String> {
+    SOLVERS
+        .iter()
+        .find(|entry| entry.year == year && entry.day == day)
+        .map(|entry| entry.create)
+        .ok_or_else(|| format!("No solvers implemented for year {} day {}", year, day))
+}
+
+pub fn years() -> Vec<u32> {
+    let mut years: Vec<u32> = all().map(|(year, _)| year).collect();
+    years.dedup();
+    years
+}
+
+pub fn days(year: u32) -> Vec<u32> {
+    all()
+        .filter(|&(y, _)| y == year)
+        .map(|(_, day)| day)
+        .collect()
+}
+
+pub fn all() -> impl Iterator<Item = (u32, u32)> {
+    SOLVERS.iter().map(|entry| (entry.year, entry.day))
+}
+
+/// The given days, or every implemented day of the year.
+pub fn resolve_days(year: u32, days: Option<RangeInclusive<u32>>) -> Result<Vec<u32>, String> {
+    match days {
+        Some(days) => Ok(days.collect()),
+        None => {
+            let days = self::days(year);
+            if days.is_empty() {
+                return Err(format!("No solvers implemented for year {}", year));
+            }
+            Ok(days)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solvers_are_sorted_and_unique() {
+        let pairs: Vec<(u32, u32)> = all().collect();
+        assert!(pairs.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn get_solver_finds_registered_days() {
+        assert!(get_solver(2024, 9).is_ok());
+        assert!(get_solver(1999, 1).is_err());
+        assert!(days(2021).contains(&13));
+    }
+}
@@ -0,0 +1,222 @@
+use crate::cli::Format;
+use crate::solver::Answer;
+use std::fmt::Write;
+
+/// A single field of a machine-readable record.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    Integer(i128),
+    Float(f64),
+    Text(String),
+    Null,
+}
+
+/// One row of output, as named fields in column order.
+pub type Record = Vec<(&'static str, Value)>;
+
+macro_rules! impl_from_integer {
+    ($($source:ty),*) => {
+        $(
+            impl From<$source> for Value {
+                fn from(value: $source) -> Self {
+                    Value::Integer(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(u32, u64, u128, usize, i64);
+
+impl From<f64> for Value {
+    fn from(value: f64) -> Self {
+        Value::Float(value)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        Value::Text(value.to_string())
+    }
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Self {
+        Value::Text(value)
+    }
+}
+
+/// Integer answers stay numbers, everything else is text.
+impl From<&Answer> for Value {
+    fn from(answer: &Answer) -> Self {
+        match answer {
+            Answer::Signed(value) => Value::from(*value),
+            Answer::Unsigned(value) => Value::from(*value),
+            _ => Value::Text(answer.to_string()),
+        }
+    }
+}
+
+impl<T: Into<Value>> From<Option<T>> for Value {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Value::Null, Into::into)
+    }
+}
+
+/// Prints records as JSON or CSV. Text output is left to each command.
+pub fn print(format: Format, records: &[Record]) {
+    match format {
+        Format::Text => {}
+        Format::Json => println!("{}", to_json(records)),
+        Format::Csv => print!("{}", to_csv(records)),
+    }
+}
+
+/// A JSON array of objects.
+pub fn to_json(records: &[Record]) -> String {
+    let mut output = String::from("[");
+    for (index, record) in records.iter().enumerate() {
+        output.push_str(if index == 0 { "\n  {" } else { ",\n  {" });
+        for (field, (name, value)) in record.iter().enumerate() {
+            if field > 0 {
+                output.push_str(", ");
+            }
+            write!(output, "{}: {}", json_string(name), json_value(value)).unwrap();
+        }
+        output.push('}');
+    }
+    output.push_str(if records.is_empty() { "]" } else { "\n]" });
+    output
+}
+
+fn json_value(value: &Value) -> String {
+    match value {
+        Value::Integer(value) => value.to_string(),
+        Value::Float(value) if value.is_finite() => format!("{:.1}", value),
+        Value::Float(_) | Value::Null => "null".to_string(),
+        Value::Text(text) => json_string(text),
+    }
+}
+
+fn json_string(text: &str) -> String {
+    let mut output = String::from("\"");
+    for character in text.chars() {
+        match character {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\n' => output.push_str("\\n"),
+            '\r' => output.push_str("\\r"),
+            '\t' => output.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(output, "\\u{:04x}", c as u32).unwrap(),
+            c => output.push(c),
+        }
+    }
+    output.push('"');
+    output
+}
+
+/// CSV with a header row taken from the first record's field names.
+pub fn to_csv(records: &[Record]) -> String {
+    let mut output = String::new();
+    if let Some(first) = records.first() {
+        let header: Vec<&str> = first.iter().map(|(name, _)| *name).collect();
+        writeln!(output, "{}", header.join(",")).unwrap();
+    }
+    for record in records {
+        let row: Vec<String> = record.iter().map(|(_, value)| csv_value(value)).collect();
+        writeln!(output, "{}", row.join(",")).unwrap();
+    }
+    output
+}
+
+/// Reads CSV as written by `to_csv`, as rows of fields.
+pub fn parse_csv(text: &str) -> Vec<Vec<String>> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut characters = text.chars().peekable();
+    while let Some(character) = characters.next() {
+        match character {
+            '"' if quoted && characters.peek() == Some(&'"') => {
+                field.push('"');
+                characters.next();
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => row.push(std::mem::take(&mut field)),
+            '\n' if !quoted => {
+                row.push(std::mem::take(&mut field));
+                rows.push(std::mem::take(&mut row));
+            }
+            character => field.push(character),
+        }
+    }
+    if !field.is_empty() || !row.is_empty() {
+        row.push(field);
+        rows.push(row);
+    }
+    rows
+}
+
+fn csv_value(value: &Value) -> String {
+    match value {
+        Value::Integer(value) => value.to_string(),
+        Value::Float(value) if value.is_finite() => format!("{:.1}", value),
+        Value::Float(_) | Value::Null => String::new(),
+        Value::Text(text) if text.contains([',', '"', '\n', '\r']) => {
+            format!("\"{}\"", text.replace('"', "\"\""))
+        }
+        Value::Text(text) => text.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn records() -> Vec<Record> {
+        vec![
+            vec![
+                ("day", Value::from(1u32)),
+                ("answer", Value::from(&Answer::from("a,\"b\"\nc"))),
+                ("mean_ns", Value::from(12.25)),
+            ],
+            vec![
+                ("day", Value::from(2u32)),
+                ("answer", Value::from(None::<&str>)),
+                ("mean_ns", Value::from(f64::NAN)),
+            ],
+        ]
+    }
+
+    #[test]
+    fn writes_json() {
+        assert_eq!(
+            to_json(&records()),
+            "[\n  {\"day\": 1, \"answer\": \"a,\\\"b\\\"\\nc\", \"mean_ns\": 12.2},\n  \
+             {\"day\": 2, \"answer\": null, \"mean_ns\": null}\n]"
+        );
+        assert_eq!(to_json(&[]), "[]");
+    }
+
+    #[test]
+    fn writes_csv() {
+        assert_eq!(
+            to_csv(&records()),
+            "day,answer,mean_ns\n1,\"a,\"\"b\"\"\nc\",12.2\n2,,\n"
+        );
+    }
+
+    #[test]
+    fn reads_written_csv() {
+        let rows = parse_csv(&to_csv(&records()));
+        assert_eq!(
+            rows,
+            vec![
+                vec!["day", "answer", "mean_ns"],
+                vec!["1", "a,\"b\"\nc", "12.2"],
+                vec!["2", "", ""],
+            ]
+        );
+    }
+}
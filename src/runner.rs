@@ -0,0 +1,223 @@
+use crate::cli::Part;
+use crate::error::{Error, Result};
+use crate::input::{self, InputSource};
+use crate::memory::{self, Usage};
+use crate::registry::{self, CreateSolver};
+use crate::solver::{Answer, AoCSolver};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+pub struct PartRun {
+    pub part: Part,
+    pub answer: Result<Answer>,
+    pub time: Duration,
+    /// Only counted when enabled with `memory::enable`.
+    pub memory: Option<Usage>,
+}
+
+/// Answers and timings from a single run of a day.
+pub struct DayRun {
+    /// Time spent reading the input, when the caller measured it.
+    pub read_time: Duration,
+    pub parse_time: Duration,
+    pub parse_memory: Option<Usage>,
+    pub parts: Vec<PartRun>,
+    pub total_time: Duration,
+}
+
+impl DayRun {
+    pub fn part(&self, part: Part) -> Option<&PartRun> {
+        self.parts.iter().find(|run| run.part == part)
+    }
+
+    pub fn first_error(&self) -> Option<&crate::error::Error> {
+        self.parts.iter().find_map(|run| run.answer.as_ref().err())
+    }
+}
+
+/// The parts to run when `part` is given, or both when it isn't.
+pub fn selected_parts(part: Option<Part>) -> Vec<Part> {
+    match part {
+        Some(part) => vec![part],
+        None => vec![Part::One, Part::Two],
+    }
+}
+
+pub fn solve_part(solver: &dyn AoCSolver, part: Part) -> Result<Answer> {
+    match part {
+        Part::One => solver.solve_part_1(),
+        Part::Two => solver.solve_part_2(),
+    }
+}
+
+/// Parses the input and solves the selected parts, timing each phase. Only a
+/// failure to parse is returned as an error, failed parts are kept in the run.
+///
+/// The day runs on its own thread so a panic is reported as `Error::Panic`,
+/// and a phase that takes longer than `timeout` as `Error::Timeout`. The parts
+/// after a timeout are skipped, and the timed out one is left running in the
+/// background as threads can't be stopped.
+pub fn run_day(
+    create_solver: CreateSolver,
+    input: &str,
+    part: Option<Part>,
+    timeout: Option<Duration>,
+) -> Result<DayRun> {
+    let total_start = Instant::now();
+    let parts = selected_parts(part);
+
+    let (parsed_sender, parsed) = mpsc::channel();
+    let (solved_sender, solved) = mpsc::channel();
+    let input = input.to_string();
+    let worker_parts = parts.clone();
+    thread::spawn(move || {
+        let start = Instant::now();
+        let (solver, parse_memory) = memory::measure(|| isolate(|| create_solver(&input)));
+        let parse_time = start.elapsed();
+        let solver = match solver {
+            Ok(solver) => solver,
+            Err(error) => {
+                let _ = parsed_sender.send((Err(error), parse_time, parse_memory));
+                return;
+            }
+        };
+        let _ = parsed_sender.send((Ok(()), parse_time, parse_memory));
+
+        for part in worker_parts {
+            let start = Instant::now();
+            let (answer, usage) = memory::measure(|| isolate(|| solve_part(solver.as_ref(), part)));
+            let time = start.elapsed();
+            if solved_sender.send((answer, time, usage)).is_err() {
+                return;
+            }
+        }
+    });
+
+    let (result, parse_time, parse_memory) = receive(&parsed, timeout)?;
+    result?;
+
+    let mut part_runs = Vec::new();
+    for part in parts {
+        let start = Instant::now();
+        match receive(&solved, timeout) {
+            Ok((answer, time, memory)) => part_runs.push(PartRun {
+                part,
+                answer,
+                time,
+                memory,
+            }),
+            Err(error) => {
+                part_runs.push(PartRun {
+                    part,
+                    answer: Err(error),
+                    time: start.elapsed(),
+                    memory: None,
+                });
+                break;
+            }
+        }
+    }
+
+    Ok(DayRun {
+        read_time: Duration::default(),
+        parse_time,
+        parse_memory,
+        parts: part_runs,
+        total_time: total_start.elapsed(),
+    })
+}
+
+/// Runs a day on its committed input. Failed parts are kept in the run.
+pub fn run_committed(
+    year: u32,
+    day: u32,
+    part: Option<Part>,
+    timeout: Option<Duration>,
+) -> Result<DayRun, String> {
+    run_from(year, day, part, &InputSource::Default, timeout)
+}
+
+pub fn run_from(
+    year: u32,
+    day: u32,
+    part: Option<Part>,
+    input: &InputSource,
+    timeout: Option<Duration>,
+) -> Result<DayRun, String> {
+    let create_solver = registry::get_solver(year, day)?;
+    let start = Instant::now();
+    let input = input::read_input(year, day, input).map_err(|error| error.to_string())?;
+    let read_time = start.elapsed();
+    let mut run =
+        run_day(create_solver, &input, part, timeout).map_err(|error| error.to_string())?;
+    run.read_time = read_time;
+    Ok(run)
+}
+
+/// Runs `f`, turning a panic into an error with the panic's message.
+fn isolate<T, F: FnOnce() -> Result<T>>(f: F) -> Result<T> {
+    panic::catch_unwind(AssertUnwindSafe(f)).unwrap_or_else(|payload| {
+        let message = match payload.downcast::<String>() {
+            Ok(message) => *message,
+            Err(payload) => match payload.downcast::<&str>() {
+                Ok(message) => message.to_string(),
+                Err(_) => "unknown cause".to_string(),
+            },
+        };
+        Err(Error::Panic(message))
+    })
+}
+
+/// Waits for the worker's next result, for at most `timeout` when one is given.
+fn receive<T>(receiver: &Receiver<T>, timeout: Option<Duration>) -> Result<T> {
+    let received = match timeout {
+        Some(timeout) => receiver.recv_timeout(timeout),
+        None => receiver.recv().map_err(RecvTimeoutError::from),
+    };
+    received.map_err(|error| match error {
+        RecvTimeoutError::Timeout => Error::Timeout,
+        RecvTimeoutError::Disconnected => Error::Panic("the solver thread stopped".to_string()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Misbehaving;
+
+    impl AoCSolver for Misbehaving {
+        fn solve_part_1(&self) -> Result<Answer> {
+            panic!("part 1 is broken");
+        }
+
+        fn solve_part_2(&self) -> Result<Answer> {
+            thread::sleep(Duration::from_secs(60));
+            Ok(Answer::from(2))
+        }
+    }
+
+    fn create(input: &str) -> Result<Box<dyn AoCSolver>> {
+        if input.is_empty() {
+            panic!("no input");
+        }
+        Ok(Box::new(Misbehaving))
+    }
+
+    #[test]
+    fn reports_panics_and_timeouts() {
+        let timeout = Some(Duration::from_millis(50));
+        let run = run_day(create, "input", None, timeout).unwrap();
+        let errors: Vec<String> = run
+            .parts
+            .iter()
+            .map(|part_run| part_run.answer.as_ref().unwrap_err().to_string())
+            .collect();
+        assert_eq!(errors, vec!["PANIC: part 1 is broken", "TIMEOUT"]);
+
+        let error = run_day(create, "", None, timeout).err().unwrap();
+        assert_eq!(error.to_string(), "PANIC: no input");
+    }
+}
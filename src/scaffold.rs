@@ -0,0 +1,64 @@
+use crate::error::Result;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+const TEMPLATE: &str = include_str!("template.rs");
+
+/// Creates `src/<year>/day_XX.rs` from the template along with an empty input
+/// and example. The registry picks the day up on the next build. Returns the
+/// files that were created.
+pub fn new_day(src_dir: &Path, year: u32, day: u32) -> Result<Vec<PathBuf>> {
+    let year_dir = src_dir.join(year.to_string());
+    let solver_path = year_dir.join(format!("day_{:02}.rs", day));
+    if solver_path.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already exists", solver_path.display()),
+        )
+        .into());
+    }
+
+    fs::create_dir_all(&year_dir)?;
+    let mut created = Vec::new();
+    let files = [
+        (solver_path, TEMPLATE),
+        (year_dir.join(format!("day_{:02}.txt", day)), ""),
+        (year_dir.join(format!("day_{:02}_example.txt", day)), ""),
+    ];
+    for (path, contents) in files.iter() {
+        // Inputs may have been saved before the solver was started
+        if path.exists() {
+            continue;
+        }
+        let mut file = OpenOptions::new().write(true).create_new(true).open(path)?;
+        file.write_all(contents.as_bytes())?;
+        created.push(path.clone());
+    }
+    Ok(created)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn creates_day_and_refuses_to_overwrite() {
+        let src_dir = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&src_dir);
+
+        let created = new_day(&src_dir, 2025, 3).unwrap();
+        assert_eq!(
+            created,
+            vec![
+                src_dir.join("2025/day_03.rs"),
+                src_dir.join("2025/day_03.txt"),
+                src_dir.join("2025/day_03_example.txt"),
+            ]
+        );
+        assert_eq!(fs::read_to_string(&created[0]).unwrap(), TEMPLATE);
+        assert!(new_day(&src_dir, 2025, 3).is_err());
+
+        fs::remove_dir_all(&src_dir).unwrap();
+    }
+}
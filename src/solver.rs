@@ -1,4 +1,198 @@
+use crate::error::Result;
+use std::fmt::{self, Display};
+
+/// A day split into parsing its input and solving each part from the parsed
+/// input, so the phases can be timed apart and the input parsed only once.
+pub trait Day {
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+    fn part_1(input: &Self::Input) -> Result<Answer>;
+    fn part_2(input: &Self::Input) -> Result<Answer>;
+}
+
+/// A solver holding its parsed input, which is what the runner works with.
+/// Days not yet migrated to `Day` implement it directly.
 pub trait AoCSolver {
-    fn solve_part_1(&self) -> String;
-    fn solve_part_2(&self) -> String;
+    fn solve_part_1(&self) -> Result<Answer>;
+    fn solve_part_2(&self) -> Result<Answer>;
+}
+
+/// Adapts the parsed input of a `Day` to `AoCSolver`.
+pub struct Parsed<D: Day>(pub D::Input);
+
+impl<D: Day> AoCSolver for Parsed<D> {
+    fn solve_part_1(&self) -> Result<Answer> {
+        D::part_1(&self.0)
+    }
+
+    fn solve_part_2(&self) -> Result<Answer> {
+        D::part_2(&self.0)
+    }
+}
+
+/// How the registry makes a solver from a day's input. Days on `Day` get it
+/// for free; days still on `AoCSolver` implement it with their constructor.
+pub trait Register {
+    fn create(input: &str) -> Result<Box<dyn AoCSolver>>;
+}
+
+impl<D> Register for D
+where
+    D: Day + 'static,
+    D::Input: 'static,
+{
+    fn create(input: &str) -> Result<Box<dyn AoCSolver>> {
+        Ok(Box::new(Parsed::<D>(D::parse(input)?)))
+    }
+}
+
+/// Implements `Register` for a day's `Solver` that is still on `AoCSolver`,
+/// through its `Solver::from_input` constructor.
+macro_rules! register_solver {
+    () => {
+        impl $crate::solver::Register for Solver {
+            fn create(input: &str) -> $crate::error::Result<Box<dyn $crate::solver::AoCSolver>> {
+                Ok(Box::new(Solver::from_input(input)?))
+            }
+        }
+    };
+}
+
+pub(crate) use register_solver;
+
+#[derive(Clone, Debug)]
+pub enum Answer {
+    Signed(i64),
+    Unsigned(u64),
+    Text(String),
+    /// Multi-line ASCII art, one string per row.
+    Art(Vec<String>),
+}
+
+impl Answer {
+    /// Renders a grid of cells as art, using `#` for set cells and a space otherwise.
+    pub fn art<R: AsRef<[bool]>>(rows: &[R]) -> Self {
+        let rows = rows
+            .iter()
+            .map(|row| {
+                let row: String = row
+                    .as_ref()
+                    .iter()
+                    .map(|&cell| if cell { '#' } else { ' ' })
+                    .collect();
+                row.trim_end().to_string()
+            })
+            .collect();
+        Answer::Art(rows)
+    }
+
+    fn as_integer(&self) -> Option<i128> {
+        match self {
+            Answer::Signed(value) => Some(*value as i128),
+            Answer::Unsigned(value) => Some(*value as i128),
+            _ => None,
+        }
+    }
+}
+
+/// Integers compare by value regardless of signedness.
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Answer::Text(a), Answer::Text(b)) => a == b,
+            (Answer::Art(a), Answer::Art(b)) => a == b,
+            _ => match (self.as_integer(), other.as_integer()) {
+                (Some(a), Some(b)) => a == b,
+                _ => false,
+            },
+        }
+    }
+}
+
+impl Eq for Answer {}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Signed(value) => write!(f, "{}", value),
+            Answer::Unsigned(value) => write!(f, "{}", value),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Art(rows) => write!(f, "{}", rows.join("\n")),
+        }
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($variant:ident, $target:ty, $($source:ty),*) => {
+        $(
+            impl From<$source> for Answer {
+                fn from(value: $source) -> Self {
+                    Answer::$variant(value as $target)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(Signed, i64, i8, i16, i32, i64, isize);
+impl_from_integer!(Unsigned, u64, u8, u16, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integers_compare_numerically() {
+        assert_eq!(Answer::from(42u32), Answer::from(42i64));
+        assert_ne!(Answer::from(-1i32), Answer::from(u64::MAX));
+        assert_ne!(Answer::from(42u32), Answer::from("42"));
+        assert_eq!(Answer::from("42"), Answer::from("42"));
+    }
+
+    struct Lengths;
+
+    impl Day for Lengths {
+        type Input = Vec<usize>;
+
+        fn parse(input: &str) -> Result<Self::Input> {
+            Ok(input.lines().map(str::len).collect())
+        }
+
+        fn part_1(lengths: &Self::Input) -> Result<Answer> {
+            Ok(lengths.len().into())
+        }
+
+        fn part_2(lengths: &Self::Input) -> Result<Answer> {
+            Ok(lengths.iter().sum::<usize>().into())
+        }
+    }
+
+    #[test]
+    fn days_solve_through_the_adapter() {
+        let solver = Lengths::create("ab\ncde").unwrap();
+        assert_eq!(solver.solve_part_1().unwrap(), Answer::from(2u32));
+        assert_eq!(solver.solve_part_2().unwrap(), Answer::from(5u32));
+    }
+
+    #[test]
+    fn art_renders_rows() {
+        let answer = Answer::art(&[
+            vec![true, false, true, false],
+            vec![false, true, false, false],
+        ]);
+        assert_eq!(answer.to_string(), "# #\n #");
+    }
 }
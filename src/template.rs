@@ -1,37 +1,20 @@
-use std::{
-    fs::File,
-    io::{BufRead, BufReader},
-};
+use crate::error::Result;
+use crate::solver::{Answer, Day};
 
-use crate::solver::AoCSolver;
+pub struct Solver;
 
-pub struct Solver {
-    lines: Vec<String>,
-}
+impl Day for Solver {
+    type Input = Vec<String>;
 
-impl Solver {
-    pub fn create() -> Self {
-        let file = File::open("src/2020/day_01.txt").unwrap();
-        let reader = BufReader::new(file);
-        let lines = reader.lines().map(|line| line.unwrap()).collect();
-        Solver { lines }
+    fn parse(input: &str) -> Result<Self::Input> {
+        return Ok(input.lines().map(String::from).collect());
     }
-}
 
-impl AoCSolver for Solver {
-    fn solve_part_1(&self) -> String {
-        return "".to_string();
+    fn part_1(lines: &Self::Input) -> Result<Answer> {
+        return Ok(lines.len().into());
     }
 
-    fn solve_part_2(&self) -> String {
-        return "".to_string();
+    fn part_2(lines: &Self::Input) -> Result<Answer> {
+        return Ok(lines.len().into());
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test() {}
-}
@@ -0,0 +1,343 @@
+use crate::util::direction::Direction8;
+use crate::util::grid::Grid;
+use crate::util::point_2d::Point2D;
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+/// Decides whether a cell is alive after a step, from whether it is alive now
+/// and how many of its neighbours are.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Rule {
+    /// Bit `n` is set when a dead cell with `n` live neighbours comes alive.
+    birth: u128,
+    /// Bit `n` is set when a live cell with `n` live neighbours stays alive.
+    survival: u128,
+}
+
+impl Rule {
+    /// A rule from the neighbour counts that bring a dead cell to life and
+    /// keep a live one alive, so the Game of Life is `Rule::new([3], [2, 3])`.
+    pub fn new<B, S>(birth: B, survival: S) -> Rule
+    where
+        B: IntoIterator<Item = usize>,
+        S: IntoIterator<Item = usize>,
+    {
+        Rule {
+            birth: mask(birth),
+            survival: mask(survival),
+        }
+    }
+
+    pub fn next(self, alive: bool, live_neighbours: usize) -> bool {
+        let mask = if alive { self.survival } else { self.birth };
+        live_neighbours < 128 && mask & 1 << live_neighbours != 0
+    }
+
+    /// The number of live neighbours at which counting can stop, because no
+    /// larger count brings a dead cell to life or keeps a live one alive.
+    pub fn limit(self, alive: bool) -> usize {
+        let mask = if alive { self.survival } else { self.birth };
+        (128 - mask.leading_zeros()) as usize
+    }
+}
+
+fn mask<I: IntoIterator<Item = usize>>(counts: I) -> u128 {
+    counts
+        .into_iter()
+        .filter(|&count| count < 128)
+        .fold(0, |mask, count| mask | 1 << count)
+}
+
+/// Steps a set of live cells once, given the neighbours of each cell. Only
+/// live cells and their neighbours are looked at, so the space is unbounded.
+pub fn step_sparse<C, N, I>(live: &HashSet<C>, neighbours: N, rule: Rule) -> HashSet<C>
+where
+    C: Copy + Eq + Hash,
+    N: Fn(C) -> I,
+    I: IntoIterator<Item = C>,
+{
+    let mut counts = HashMap::<C, usize>::with_capacity(live.len() * 4);
+    for &cell in live {
+        for neighbour in neighbours(cell) {
+            *counts.entry(neighbour).or_insert(0) += 1;
+        }
+    }
+
+    let mut next: HashSet<C> = counts
+        .iter()
+        .filter(|(cell, count)| rule.next(live.contains(cell), **count))
+        .map(|(cell, _)| *cell)
+        .collect();
+    // Live cells without a single live neighbour aren't counted above.
+    if rule.next(true, 0) {
+        next.extend(live.iter().filter(|cell| !counts.contains_key(cell)));
+    }
+    next
+}
+
+/// The `3^N - 1` cells around a cell in `N` dimensions, diagonals included.
+pub struct Moore<const N: usize> {
+    offsets: Vec<[i32; N]>,
+}
+
+impl<const N: usize> Moore<N> {
+    pub fn new() -> Self {
+        let mut offsets = vec![[0; N]];
+        for dimension in 0..N {
+            offsets = offsets
+                .iter()
+                .flat_map(|offset| {
+                    (-1..=1).map(move |delta| {
+                        let mut offset = *offset;
+                        offset[dimension] = delta;
+                        offset
+                    })
+                })
+                .collect();
+        }
+        offsets.retain(|offset| offset.iter().any(|&delta| delta != 0));
+        Moore { offsets }
+    }
+
+    pub fn neighbours(&self, cell: [i32; N]) -> impl Iterator<Item = [i32; N]> + '_ {
+        self.offsets.iter().map(move |offset| {
+            let mut neighbour = cell;
+            for (value, delta) in neighbour.iter_mut().zip(offset) {
+                *value += delta;
+            }
+            neighbour
+        })
+    }
+}
+
+impl<const N: usize> Default for Moore<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The first cell in each of the eight directions from `point` for which
+/// `is_cell` holds, looking at most `max_distance` steps away. With a
+/// distance of 1 this is the Moore neighbourhood on a grid.
+pub fn line_of_sight<'a, T, F>(
+    grid: &'a Grid<T>,
+    point: Point2D,
+    max_distance: usize,
+    is_cell: F,
+) -> impl Iterator<Item = Point2D> + 'a
+where
+    F: Fn(&T) -> bool + 'a,
+{
+    Direction8::iter().filter_map(move |direction| {
+        grid.ray(point, direction.offset())
+            .skip(1)
+            .take(max_distance)
+            .find(|neighbour| is_cell(&grid[*neighbour]))
+    })
+}
+
+/// An automaton on a grid where only some of the cells take part, such as
+/// the seats of a waiting area. The cells are numbered in the order they
+/// appear in the grid, which is also the order of a state, holding whether
+/// each of them is alive, or how charged it is for `cascade`. The neighbours
+/// of every cell are found once, when it's created.
+pub struct Dense {
+    cells: Vec<Point2D>,
+    /// The neighbours of cell `i` are `neighbours[starts[i]..starts[i + 1]]`.
+    starts: Vec<usize>,
+    neighbours: Vec<usize>,
+}
+
+impl Dense {
+    /// Takes part with every cell of `grid` for which `is_cell` holds, with the
+    /// neighbours given by `neighbours`. Neighbours that don't take part are
+    /// left out.
+    pub fn new<T, C, N, I>(grid: &Grid<T>, is_cell: C, mut neighbours: N) -> Dense
+    where
+        C: Fn(&T) -> bool,
+        N: FnMut(Point2D) -> I,
+        I: IntoIterator<Item = Point2D>,
+    {
+        let cells: Vec<Point2D> = grid
+            .iter()
+            .filter(|(_, cell)| is_cell(cell))
+            .map(|(point, _)| point)
+            .collect();
+        let mut numbers = grid.map(|_| None);
+        for (number, &point) in cells.iter().enumerate() {
+            numbers[point] = Some(number);
+        }
+
+        let mut starts = Vec::with_capacity(cells.len() + 1);
+        let mut numbered = Vec::with_capacity(cells.len() * 8);
+        starts.push(0);
+        for &point in cells.iter() {
+            numbered.extend(neighbours(point).into_iter().filter_map(|n| numbers[n]));
+            starts.push(numbered.len());
+        }
+        Dense {
+            cells,
+            starts,
+            neighbours: numbered,
+        }
+    }
+
+    /// The numbers of the cells around cell `cell`.
+    pub fn neighbours(&self, cell: usize) -> &[usize] {
+        &self.neighbours[self.starts[cell]..self.starts[cell + 1]]
+    }
+
+    /// A state with `value` of each cell's point, such as whether it's alive.
+    pub fn state<S, F: FnMut(Point2D) -> S>(&self, mut value: F) -> Vec<S> {
+        self.cells.iter().map(|&point| value(point)).collect()
+    }
+
+    /// Steps every cell at once from `live` into `next`, returning whether any
+    /// of them changed.
+    pub fn step(&self, live: &[bool], next: &mut [bool], rule: Rule) -> bool {
+        let mut changed = false;
+        for (cell, &alive) in live.iter().enumerate() {
+            let limit = rule.limit(alive);
+            let mut count = 0;
+            for &neighbour in self.neighbours(cell) {
+                if count == limit {
+                    break;
+                }
+                if live[neighbour] {
+                    count += 1;
+                }
+            }
+            next[cell] = rule.next(alive, count);
+            changed |= next[cell] != alive;
+        }
+        changed
+    }
+
+    /// Steps a threshold automaton instead of a live or dead one: every cell
+    /// gains a unit of charge, and a cell whose charge reaches `threshold`
+    /// fires, giving a unit to each of its neighbours, which can make them
+    /// fire in turn. A cell fires at most once per step, and the cells that
+    /// fired end the step with no charge. Returns how many cells fired.
+    pub fn cascade(&self, charge: &mut [u8], threshold: u8) -> usize {
+        let mut firing: Vec<usize> = Vec::new();
+        for (cell, charge) in charge.iter_mut().enumerate() {
+            *charge += 1;
+            if *charge == threshold {
+                firing.push(cell);
+            }
+        }
+
+        let mut fired = 0;
+        while let Some(cell) = firing.pop() {
+            fired += 1;
+            for &neighbour in self.neighbours(cell) {
+                charge[neighbour] += 1;
+                if charge[neighbour] == threshold {
+                    firing.push(neighbour);
+                }
+            }
+        }
+
+        for charge in charge.iter_mut() {
+            if *charge >= threshold {
+                *charge = 0;
+            }
+        }
+        fired
+    }
+
+    /// Steps until nothing changes anymore, returning the final state.
+    pub fn run_until_stable(&self, mut live: Vec<bool>, rule: Rule) -> Vec<bool> {
+        let mut next = live.clone();
+        while self.step(&live, &mut next, rule) {
+            std::mem::swap(&mut live, &mut next);
+        }
+        live
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn applies_rules() {
+        let life = Rule::new([3], [2, 3]);
+        assert!(life.next(false, 3));
+        assert!(!life.next(false, 2));
+        assert!(life.next(true, 2));
+        assert!(!life.next(true, 4));
+        assert!(!life.next(true, 200));
+        assert_eq!(life.limit(false), 4);
+        assert_eq!(life.limit(true), 4);
+        assert_eq!(Rule::new([0], 0..4).limit(false), 1);
+    }
+
+    #[test]
+    fn steps_a_sparse_glider() {
+        let moore = Moore::<2>::new();
+        assert_eq!(moore.neighbours([0, 0]).count(), 8);
+        assert_eq!(Moore::<4>::new().neighbours([0; 4]).count(), 80);
+
+        let mut live: HashSet<[i32; 2]> = vec![[1, 0], [2, 1], [0, 2], [1, 2], [2, 2]]
+            .into_iter()
+            .collect();
+        let start = live.clone();
+        for _ in 0..4 {
+            live = step_sparse(&live, |cell| moore.neighbours(cell), Rule::new([3], [2, 3]));
+        }
+        let moved: HashSet<[i32; 2]> = start.iter().map(|[x, y]| [x + 1, y + 1]).collect();
+        assert_eq!(live, moved);
+    }
+
+    #[test]
+    fn steps_a_dense_blinker() {
+        let grid = Grid::parse(".#.\n.#.\n.#.", |c| Some(c == '#')).unwrap();
+        let automaton = Dense::new(&grid, |_| true, |point| grid.neighbours_8(point));
+        assert_eq!(automaton.neighbours(4).len(), 8);
+        let live = automaton.state(|point| grid[point]);
+        let mut next = vec![false; live.len()];
+        assert!(automaton.step(&live, &mut next, Rule::new([3], [2, 3])));
+        let expected = [false, false, false, true, true, true, false, false, false];
+        assert_eq!(next, expected);
+    }
+
+    #[test]
+    fn skips_cells_that_do_not_take_part() {
+        let grid = Grid::parse("#.#\n.#.\n#.#", Some).unwrap();
+        let automaton = Dense::new(&grid, |&c| c == '#', |point| grid.neighbours_8(point));
+        assert_eq!(automaton.state(|_| true).len(), 5);
+        assert_eq!(automaton.neighbours(2), &[1, 4, 3, 0]);
+        // A dead cell with four live neighbours comes alive and then stays.
+        let live = automaton.state(|point| point != Point2D::new(1, 1));
+        let stable = automaton.run_until_stable(live, Rule::new([4], [0, 1, 4]));
+        assert_eq!(stable, vec![true; 5]);
+    }
+
+    #[test]
+    fn cascades_firing_cells() {
+        let grid = Grid::parse("979\n000\n000", |c| c.to_digit(10)).unwrap();
+        let automaton = Dense::new(&grid, |_| true, |point| grid.neighbours_4(point));
+        let mut charge = automaton.state(|point| grid[point] as u8);
+        // Both nines fire, which sets off the cell between them.
+        assert_eq!(automaton.cascade(&mut charge, 10), 3);
+        assert_eq!(charge, vec![0, 0, 0, 2, 2, 2, 1, 1, 1]);
+        assert_eq!(automaton.cascade(&mut charge, 10), 0);
+        assert_eq!(charge, vec![1, 1, 1, 3, 3, 3, 2, 2, 2]);
+    }
+
+    #[test]
+    fn looks_past_cells_that_do_not_take_part() {
+        let grid = Grid::parse("L.#\n...\n#.L", Some).unwrap();
+        let seen: Vec<Point2D> =
+            line_of_sight(&grid, Point2D::new(0, 0), usize::MAX, |&c| c != '.').collect();
+        assert_eq!(
+            seen,
+            vec![Point2D::new(2, 0), Point2D::new(2, 2), Point2D::new(0, 2)]
+        );
+        assert_eq!(
+            line_of_sight(&grid, Point2D::new(0, 0), 1, |&c| c != '.').count(),
+            0
+        );
+    }
+}
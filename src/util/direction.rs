@@ -0,0 +1,193 @@
+use crate::util::point_2d::Point2D;
+
+/// One of the four orthogonal directions, on a grid with `y` going down.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Direction4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+/// One of the four orthogonal or four diagonal directions, on a grid with `y`
+/// going down.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction4 {
+    /// Every direction, clockwise from up.
+    pub const ALL: [Direction4; 4] = [
+        Direction4::Up,
+        Direction4::Right,
+        Direction4::Down,
+        Direction4::Left,
+    ];
+
+    pub fn iter() -> impl Iterator<Item = Direction4> {
+        Self::ALL.iter().copied()
+    }
+
+    /// Parses `U/D/L/R`, `N/E/S/W`, `^>v<` or the name of a direction, such
+    /// as `up` or `north`, ignoring case.
+    pub fn parse(text: &str) -> Option<Direction4> {
+        let direction = match text.to_ascii_lowercase().as_str() {
+            "u" | "n" | "^" | "up" | "north" => Direction4::Up,
+            "r" | "e" | ">" | "right" | "east" => Direction4::Right,
+            "d" | "s" | "v" | "down" | "south" => Direction4::Down,
+            "l" | "w" | "<" | "left" | "west" => Direction4::Left,
+            _ => return None,
+        };
+        Some(direction)
+    }
+
+    /// Turns clockwise by a number of quarter turns, counterclockwise when it
+    /// is negative.
+    pub fn turn(self, quarter_turns: isize) -> Direction4 {
+        Self::ALL[(self as isize + quarter_turns).rem_euclid(4) as usize]
+    }
+
+    pub fn turn_right(self) -> Direction4 {
+        self.turn(1)
+    }
+
+    #[allow(dead_code)]
+    pub fn turn_left(self) -> Direction4 {
+        self.turn(-1)
+    }
+
+    #[allow(dead_code)]
+    pub fn turn_around(self) -> Direction4 {
+        self.turn(2)
+    }
+
+    /// The offset of a single step in this direction.
+    pub fn offset(self) -> Point2D {
+        match self {
+            Direction4::Up => Point2D::new(0, -1),
+            Direction4::Right => Point2D::new(1, 0),
+            Direction4::Down => Point2D::new(0, 1),
+            Direction4::Left => Point2D::new(-1, 0),
+        }
+    }
+}
+
+impl Direction8 {
+    /// Every direction, clockwise from up.
+    pub const ALL: [Direction8; 8] = [
+        Direction8::Up,
+        Direction8::UpRight,
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+        Direction8::UpLeft,
+    ];
+
+    pub fn iter() -> impl Iterator<Item = Direction8> {
+        Self::ALL.iter().copied()
+    }
+
+    /// Parses anything `Direction4::parse` does, as well as the diagonal
+    /// compass points `NE/SE/SW/NW`, ignoring case.
+    #[allow(dead_code)]
+    pub fn parse(text: &str) -> Option<Direction8> {
+        let direction = match text.to_ascii_lowercase().as_str() {
+            "ne" | "northeast" => Direction8::UpRight,
+            "se" | "southeast" => Direction8::DownRight,
+            "sw" | "southwest" => Direction8::DownLeft,
+            "nw" | "northwest" => Direction8::UpLeft,
+            _ => Direction4::parse(text)?.into(),
+        };
+        Some(direction)
+    }
+
+    /// Turns clockwise by a number of eighth turns, counterclockwise when it
+    /// is negative.
+    #[allow(dead_code)]
+    pub fn turn(self, eighth_turns: isize) -> Direction8 {
+        Self::ALL[(self as isize + eighth_turns).rem_euclid(8) as usize]
+    }
+
+    /// Turns a quarter clockwise.
+    #[allow(dead_code)]
+    pub fn turn_right(self) -> Direction8 {
+        self.turn(2)
+    }
+
+    /// Turns a quarter counterclockwise.
+    #[allow(dead_code)]
+    pub fn turn_left(self) -> Direction8 {
+        self.turn(-2)
+    }
+
+    #[allow(dead_code)]
+    pub fn turn_around(self) -> Direction8 {
+        self.turn(4)
+    }
+
+    /// The offset of a single step in this direction.
+    pub fn offset(self) -> Point2D {
+        match self {
+            Direction8::Up => Point2D::new(0, -1),
+            Direction8::UpRight => Point2D::new(1, -1),
+            Direction8::Right => Point2D::new(1, 0),
+            Direction8::DownRight => Point2D::new(1, 1),
+            Direction8::Down => Point2D::new(0, 1),
+            Direction8::DownLeft => Point2D::new(-1, 1),
+            Direction8::Left => Point2D::new(-1, 0),
+            Direction8::UpLeft => Point2D::new(-1, -1),
+        }
+    }
+}
+
+impl From<Direction4> for Direction8 {
+    fn from(direction: Direction4) -> Self {
+        Direction8::ALL[direction as usize * 2]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turns() {
+        assert_eq!(Direction4::Up.turn_right(), Direction4::Right);
+        assert_eq!(Direction4::Up.turn_left(), Direction4::Left);
+        assert_eq!(Direction4::Left.turn_around(), Direction4::Right);
+        assert_eq!(Direction4::Down.turn(-7), Direction4::Left);
+        assert_eq!(Direction8::UpLeft.turn(1), Direction8::Up);
+        assert_eq!(Direction8::UpRight.turn_left(), Direction8::UpLeft);
+
+        for direction in Direction4::iter() {
+            let offset = direction.offset();
+            assert_eq!(direction.turn_right().offset(), offset.rotate_right());
+            assert_eq!(Direction8::from(direction).offset(), offset);
+        }
+    }
+
+    #[test]
+    fn parses_directions() {
+        let parse = |text| Direction4::parse(text).unwrap();
+        assert_eq!(parse("U"), Direction4::Up);
+        assert_eq!(parse("e"), Direction4::Right);
+        assert_eq!(parse("v"), Direction4::Down);
+        assert_eq!(parse("left"), Direction4::Left);
+        assert_eq!(parse("^"), Direction4::Up);
+        assert_eq!(Direction4::parse("ne"), None);
+
+        assert_eq!(Direction8::parse("SW"), Some(Direction8::DownLeft));
+        assert_eq!(Direction8::parse("<"), Some(Direction8::Left));
+        assert_eq!(Direction8::parse("nw"), Some(Direction8::UpLeft));
+    }
+}
@@ -0,0 +1,329 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+/// The number of steps from `start` to every node it can reach, going
+/// breadth first.
+#[allow(dead_code)]
+pub fn bfs<N, F, I>(start: N, mut neighbours: F) -> HashMap<N, usize>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let mut distances = HashMap::new();
+    distances.insert(start.clone(), 0);
+    let mut queue = VecDeque::new();
+    queue.push_back((start, 0));
+    while let Some((node, distance)) = queue.pop_front() {
+        for neighbour in neighbours(&node) {
+            if !distances.contains_key(&neighbour) {
+                distances.insert(neighbour.clone(), distance + 1);
+                queue.push_back((neighbour, distance + 1));
+            }
+        }
+    }
+    distances
+}
+
+/// Every node reachable from `start`, in the order a depth first search
+/// first reaches them.
+#[allow(dead_code)]
+pub fn dfs<N, F, I>(start: N, mut neighbours: F) -> Vec<N>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let mut seen = HashSet::new();
+    let mut order = Vec::new();
+    let mut stack = vec![start];
+    while let Some(node) = stack.pop() {
+        if !seen.insert(node.clone()) {
+            continue;
+        }
+        let mut next: Vec<N> = neighbours(&node)
+            .into_iter()
+            .filter(|neighbour| !seen.contains(neighbour))
+            .collect();
+        // Reversed, so the first neighbour is the first one popped.
+        next.reverse();
+        stack.extend(next);
+        order.push(node);
+    }
+    order
+}
+
+/// The cost and nodes of a cheapest path from `start` to a node for which
+/// `is_goal` holds, where `neighbours` gives each next node with the cost of
+/// the step there.
+#[allow(dead_code)]
+pub fn dijkstra<N, F, I, G>(start: N, is_goal: G, neighbours: F) -> Option<(usize, Vec<N>)>
+where
+    N: Clone + Eq + Hash + Ord,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, usize)>,
+    G: FnMut(&N) -> bool,
+{
+    astar(start, is_goal, neighbours, |_| 0)
+}
+
+/// Like `dijkstra`, but looks at the nodes most likely to be on the way
+/// first. The path is only the cheapest when `heuristic` never guesses more
+/// than the remaining cost to a goal.
+#[allow(dead_code)]
+pub fn astar<N, F, I, G, H>(
+    start: N,
+    mut is_goal: G,
+    mut neighbours: F,
+    mut heuristic: H,
+) -> Option<(usize, Vec<N>)>
+where
+    N: Clone + Eq + Hash + Ord,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, usize)>,
+    G: FnMut(&N) -> bool,
+    H: FnMut(&N) -> usize,
+{
+    let mut costs = HashMap::new();
+    let mut previous = HashMap::new();
+    let mut queue = BinaryHeap::new();
+    costs.insert(start.clone(), 0);
+    queue.push(Reverse((heuristic(&start), 0, start)));
+
+    while let Some(Reverse((_, cost, node))) = queue.pop() {
+        if costs.get(&node).is_some_and(|&best| best < cost) {
+            continue;
+        }
+        if is_goal(&node) {
+            let mut path = vec![node];
+            while let Some(before) = previous.get(path.last().unwrap()) {
+                path.push(N::clone(before));
+            }
+            path.reverse();
+            return Some((cost, path));
+        }
+        for (neighbour, step) in neighbours(&node) {
+            let next_cost = cost + step;
+            if costs.get(&neighbour).is_none_or(|&best| next_cost < best) {
+                costs.insert(neighbour.clone(), next_cost);
+                previous.insert(neighbour.clone(), node.clone());
+                queue.push(Reverse((
+                    next_cost + heuristic(&neighbour),
+                    next_cost,
+                    neighbour,
+                )));
+            }
+        }
+    }
+    None
+}
+
+/// Every path from `start` to a node for which `is_end` holds. A path only
+/// goes on to a neighbour when `can_visit` allows it, given the path so far,
+/// which is what keeps it from going round in circles. Paths stop at the
+/// first end they reach.
+#[allow(dead_code)]
+pub fn all_paths<N, F, I, E, V>(start: N, is_end: E, neighbours: F, can_visit: V) -> Vec<Vec<N>>
+where
+    N: Clone,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+    E: FnMut(&N) -> bool,
+    V: FnMut(&[N], &N) -> bool,
+{
+    let mut paths = Vec::new();
+    let mut search = PathSearch {
+        is_end,
+        neighbours,
+        can_visit,
+    };
+    search.walk(&mut vec![start], &mut |path| paths.push(path.to_vec()));
+    paths
+}
+
+/// The number of paths `all_paths` would find, without keeping them.
+pub fn count_paths<N, F, I, E, V>(start: N, is_end: E, neighbours: F, can_visit: V) -> usize
+where
+    N: Clone,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+    E: FnMut(&N) -> bool,
+    V: FnMut(&[N], &N) -> bool,
+{
+    let mut count = 0;
+    let mut search = PathSearch {
+        is_end,
+        neighbours,
+        can_visit,
+    };
+    search.walk(&mut vec![start], &mut |_| count += 1);
+    count
+}
+
+struct PathSearch<E, F, V> {
+    is_end: E,
+    neighbours: F,
+    can_visit: V,
+}
+
+impl<E, F, V> PathSearch<E, F, V> {
+    fn walk<N, I>(&mut self, path: &mut Vec<N>, found: &mut dyn FnMut(&[N]))
+    where
+        N: Clone,
+        F: FnMut(&N) -> I,
+        I: IntoIterator<Item = N>,
+        E: FnMut(&N) -> bool,
+        V: FnMut(&[N], &N) -> bool,
+    {
+        let node = path.last().unwrap().clone();
+        if (self.is_end)(&node) {
+            found(path);
+            return;
+        }
+        for neighbour in (self.neighbours)(&node) {
+            if (self.can_visit)(path, &neighbour) {
+                path.push(neighbour);
+                self.walk(path, found);
+                path.pop();
+            }
+        }
+    }
+}
+
+/// The nodes that were left over when sorting, because each of them is on or
+/// after a cycle.
+#[derive(Debug, PartialEq)]
+pub struct Cycle<N> {
+    pub nodes: Vec<N>,
+}
+
+/// Orders `nodes` so that every edge `(before, after)` has `before` first,
+/// using Kahn's algorithm. Nodes that no edge orders keep their order, and
+/// edges to nodes that aren't being sorted are ignored.
+pub fn topological_sort<N, E>(nodes: &[N], edges: E) -> Result<Vec<N>, Cycle<N>>
+where
+    N: Clone + Eq + Hash,
+    E: IntoIterator<Item = (N, N)>,
+{
+    let index: HashMap<&N, usize> = nodes.iter().enumerate().map(|(i, n)| (n, i)).collect();
+    let mut after = vec![Vec::new(); nodes.len()];
+    let mut in_degree = vec![0; nodes.len()];
+    for (before, next) in edges {
+        if let (Some(&before), Some(&next)) = (index.get(&before), index.get(&next)) {
+            after[before].push(next);
+            in_degree[next] += 1;
+        }
+    }
+
+    let mut queue: VecDeque<usize> = (0..nodes.len()).filter(|&i| in_degree[i] == 0).collect();
+    let mut sorted = Vec::with_capacity(nodes.len());
+    while let Some(node) = queue.pop_front() {
+        sorted.push(nodes[node].clone());
+        for &next in after[node].iter() {
+            in_degree[next] -= 1;
+            if in_degree[next] == 0 {
+                queue.push_back(next);
+            }
+        }
+    }
+
+    if sorted.len() < nodes.len() {
+        let nodes = (0..nodes.len())
+            .filter(|&i| in_degree[i] > 0)
+            .map(|i| nodes[i].clone())
+            .collect();
+        return Err(Cycle { nodes });
+    }
+    Ok(sorted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A small ring road, numbered clockwise, with a shortcut from 0 to 3.
+    fn roads(node: &usize) -> Vec<(usize, usize)> {
+        let mut roads = vec![((node + 1) % 6, 2), ((node + 5) % 6, 2)];
+        if *node == 0 {
+            roads.push((3, 5));
+        }
+        roads
+    }
+
+    #[test]
+    fn searches_breadth_and_depth_first() {
+        let next = |node: &usize| roads(node).into_iter().map(|(next, _)| next);
+        let distances = bfs(0, next);
+        assert_eq!(distances.len(), 6);
+        assert_eq!(distances[&3], 1);
+        assert_eq!(distances[&4], 2);
+
+        assert_eq!(dfs(0, next), vec![0, 1, 2, 3, 4, 5]);
+        assert_eq!(dfs(7, |_| Vec::new()), vec![7]);
+    }
+
+    #[test]
+    fn finds_cheapest_paths() {
+        assert_eq!(dijkstra(0, |&n| n == 3, roads), Some((5, vec![0, 3])));
+        assert_eq!(dijkstra(0, |&n| n == 2, roads), Some((4, vec![0, 1, 2])));
+        assert_eq!(dijkstra(0, |&n| n == 9, roads), None);
+
+        // Twice the number of steps around the ring to 5.
+        let guess = |n: &usize| 2 * ((n + 1) % 6).min(5 - n % 6);
+        assert_eq!(
+            astar(1, |&n| n == 5, roads, guess),
+            Some((4, vec![1, 0, 5]))
+        );
+    }
+
+    #[test]
+    fn enumerates_paths() {
+        // 0 -> 1 -> 3 and 0 -> 2 -> 3, with 1 and 2 also joined.
+        let edges = |node: &usize| match node {
+            0 => vec![1, 2],
+            1 => vec![2, 3],
+            2 => vec![1, 3],
+            _ => vec![],
+        };
+        let unvisited = |path: &[usize], next: &usize| !path.contains(next);
+        let mut paths = all_paths(0, |&n| n == 3, edges, unvisited);
+        paths.sort();
+        assert_eq!(
+            paths,
+            vec![
+                vec![0, 1, 2, 3],
+                vec![0, 1, 3],
+                vec![0, 2, 1, 3],
+                vec![0, 2, 3]
+            ]
+        );
+        assert_eq!(count_paths(0, |&n| n == 3, edges, unvisited), 4);
+        assert_eq!(
+            count_paths(
+                0,
+                |&n| n == 3,
+                edges,
+                |path, n| *n != 3 && unvisited(path, n)
+            ),
+            0
+        );
+    }
+
+    #[test]
+    fn sorts_topologically() {
+        let edges = vec![('b', 'a'), ('c', 'b'), ('c', 'x')];
+        assert_eq!(
+            topological_sort(&['a', 'b', 'c', 'd'], edges),
+            Ok(vec!['c', 'd', 'b', 'a'])
+        );
+
+        let edges = vec![('a', 'b'), ('b', 'c'), ('c', 'b')];
+        assert_eq!(
+            topological_sort(&['a', 'b', 'c'], edges),
+            Err(Cycle {
+                nodes: vec!['b', 'c']
+            })
+        );
+    }
+}
@@ -0,0 +1,250 @@
+use crate::error::{Error, Result};
+use crate::input::numbered_lines;
+use crate::util::direction::{Direction4, Direction8};
+use crate::util::point_2d::Point2D;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// A rectangular grid of cells stored row by row, with `x` going right and
+/// `y` going down from the top left corner. Points are signed, so stepping
+/// off an edge gives `None` rather than wrapping around.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Parses a grid with one row per line, turning each character into a
+    /// cell with `parse`, which returns `None` for characters it doesn't
+    /// expect.
+    pub fn parse<F: FnMut(char) -> Option<T>>(input: &str, mut parse: F) -> Result<Self> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for (line_number, line) in numbered_lines(input) {
+            let length = line.chars().count();
+            if *width.get_or_insert(length) != length {
+                let message = format!("expected {} columns", width.unwrap());
+                return Err(Error::parse(line_number, message));
+            }
+            for character in line.chars() {
+                match parse(character) {
+                    Some(cell) => cells.push(cell),
+                    None => {
+                        let message = format!("unexpected character '{}'", character);
+                        return Err(Error::parse(line_number, message));
+                    }
+                }
+            }
+            height += 1;
+        }
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point2D) -> bool {
+        point.x >= 0
+            && point.y >= 0
+            && (point.x as usize) < self.width
+            && (point.y as usize) < self.height
+    }
+
+    fn index_of(&self, point: Point2D) -> Option<usize> {
+        if self.contains(point) {
+            Some(point.y as usize * self.width + point.x as usize)
+        } else {
+            None
+        }
+    }
+
+    pub fn get(&self, point: Point2D) -> Option<&T> {
+        let index = self.index_of(point)?;
+        Some(&self.cells[index])
+    }
+
+    /// Every cell along with its point, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point2D, &T)> + '_ {
+        self.rows().enumerate().flat_map(|(y, row)| {
+            row.iter()
+                .enumerate()
+                .map(move |(x, cell)| (Point2D::new(x as isize, y as isize), cell))
+        })
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The points of row `y`, from left to right.
+    #[allow(dead_code)]
+    pub fn row(&self, y: isize) -> impl Iterator<Item = Point2D> {
+        self.ray(Point2D::new(0, y), Point2D::new(1, 0))
+    }
+
+    /// The points of column `x`, from top to bottom.
+    #[allow(dead_code)]
+    pub fn column(&self, x: isize) -> impl Iterator<Item = Point2D> {
+        self.ray(Point2D::new(x, 0), Point2D::new(0, 1))
+    }
+
+    /// The points from `start` onwards, moving by `step` each time, until
+    /// leaving the grid. `start` is the first point when it's in the grid.
+    pub fn ray(&self, start: Point2D, step: Point2D) -> impl Iterator<Item = Point2D> {
+        let (width, height) = (self.width as isize, self.height as isize);
+        let mut point = start;
+        std::iter::from_fn(move || {
+            if point.x < 0 || point.y < 0 || point.x >= width || point.y >= height {
+                return None;
+            }
+            let current = point;
+            point += step;
+            Some(current)
+        })
+    }
+
+    /// The orthogonal neighbours of a point that are in the grid.
+    pub fn neighbours_4(&self, point: Point2D) -> impl Iterator<Item = Point2D> {
+        self.neighbours(point, Direction4::iter().map(Direction4::offset))
+    }
+
+    /// The orthogonal and diagonal neighbours of a point that are in the grid.
+    pub fn neighbours_8(&self, point: Point2D) -> impl Iterator<Item = Point2D> {
+        self.neighbours(point, Direction8::iter().map(Direction8::offset))
+    }
+
+    fn neighbours<I: Iterator<Item = Point2D>>(
+        &self,
+        point: Point2D,
+        offsets: I,
+    ) -> impl Iterator<Item = Point2D> {
+        let (width, height) = (self.width as isize, self.height as isize);
+        offsets
+            .map(move |offset| point + offset)
+            .filter(move |p| p.x >= 0 && p.y >= 0 && p.x < width && p.y < height)
+    }
+
+    /// A grid of the same size with `f` applied to every cell.
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: PartialEq> Grid<T> {
+    /// The points of every cell equal to `value`, row by row.
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Point2D> + 'a {
+        self.iter()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(point, _)| point)
+    }
+}
+
+impl<T> Index<Point2D> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point2D) -> &T {
+        match self.index_of(point) {
+            Some(index) => &self.cells[index],
+            None => panic!("({}, {}) is outside the grid", point.x, point.y),
+        }
+    }
+}
+
+impl<T> IndexMut<Point2D> for Grid<T> {
+    fn index_mut(&mut self, point: Point2D) -> &mut T {
+        match self.index_of(point) {
+            Some(index) => &mut self.cells[index],
+            None => panic!("({}, {}) is outside the grid", point.x, point.y),
+        }
+    }
+}
+
+/// One line per row, without a trailing line break.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(input: &str) -> Grid<u32> {
+        Grid::parse(input, |c| c.to_digit(10)).unwrap()
+    }
+
+    #[test]
+    fn parses_and_displays() {
+        let grid = digits("123\n456");
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point2D::new(2, 1)], 6);
+        assert_eq!(grid.get(Point2D::new(3, 0)), None);
+        assert_eq!(grid.get(Point2D::new(-1, 0)), None);
+        assert_eq!(grid.to_string(), "123\n456");
+
+        assert!(Grid::parse("12\n3", |c| c.to_digit(10)).is_err());
+        assert!(Grid::parse("1x", |c| c.to_digit(10)).is_err());
+    }
+
+    #[test]
+    fn iterates_neighbours_and_rays() {
+        let grid = digits("123\n456\n789");
+        let values =
+            |points: Vec<Point2D>| -> Vec<u32> { points.iter().map(|p| grid[*p]).collect() };
+
+        assert_eq!(
+            values(grid.neighbours_4(Point2D::new(0, 0)).collect()),
+            vec![2, 4]
+        );
+        assert_eq!(
+            values(grid.neighbours_8(Point2D::new(1, 1)).collect()),
+            vec![2, 3, 6, 9, 8, 7, 4, 1]
+        );
+        assert_eq!(values(grid.row(1).collect()), vec![4, 5, 6]);
+        assert_eq!(values(grid.column(2).collect()), vec![3, 6, 9]);
+        assert_eq!(
+            values(grid.ray(Point2D::new(2, 0), Point2D::new(-1, 1)).collect()),
+            vec![3, 5, 7]
+        );
+        assert_eq!(
+            grid.ray(Point2D::new(3, 3), Point2D::new(-1, -1)).count(),
+            0
+        );
+    }
+
+    #[test]
+    fn finds_cells_by_value() {
+        let grid = digits("121\n212");
+        let found: Vec<Point2D> = grid.find_all(&2).collect();
+        assert_eq!(
+            found,
+            vec![Point2D::new(1, 0), Point2D::new(0, 1), Point2D::new(2, 1)]
+        );
+        assert_eq!(grid.find_all(&1).count(), 3);
+    }
+}
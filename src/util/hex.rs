@@ -0,0 +1,313 @@
+use std::fmt;
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// A hex in axial coordinates. Together with `s = -q - r` these are the cube
+/// coordinates of the hex, and moving to a neighbour changes two of the three
+/// by one in opposite directions.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Axial {
+    pub q: isize,
+    pub r: isize,
+}
+
+/// A hex in cube coordinates, which always add up to zero.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Cube {
+    pub q: isize,
+    pub r: isize,
+    pub s: isize,
+}
+
+/// A direction on a hex grid with pointy tops, where the hexes of a row touch
+/// on their east and west sides.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Pointy {
+    East,
+    NorthEast,
+    NorthWest,
+    West,
+    SouthWest,
+    SouthEast,
+}
+
+/// A direction on a hex grid with flat tops, where the hexes of a column touch
+/// on their north and south sides.
+#[allow(dead_code)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Flat {
+    North,
+    NorthWest,
+    SouthWest,
+    South,
+    SouthEast,
+    NorthEast,
+}
+
+impl Axial {
+    pub const ORIGIN: Axial = Axial { q: 0, r: 0 };
+
+    pub const fn new(q: isize, r: isize) -> Self {
+        Axial { q, r }
+    }
+
+    pub fn s(self) -> isize {
+        -self.q - self.r
+    }
+
+    /// The six hexes around this one, in the order of `Pointy::ALL`.
+    pub fn neighbours(self) -> impl Iterator<Item = Axial> {
+        Pointy::ALL
+            .iter()
+            .map(move |direction| self + direction.offset())
+    }
+
+    /// The number of steps between two hexes.
+    #[allow(dead_code)]
+    pub fn distance(self, other: Axial) -> usize {
+        let difference = self - other;
+        (difference.q.unsigned_abs() + difference.r.unsigned_abs() + difference.s().unsigned_abs())
+            / 2
+    }
+
+    /// The hexes exactly `radius` steps away, going around counterclockwise.
+    #[allow(dead_code)]
+    pub fn ring(self, radius: usize) -> Vec<Axial> {
+        if radius == 0 {
+            return vec![self];
+        }
+        let mut hex = self + Pointy::SouthWest.offset() * radius as isize;
+        let mut ring = Vec::with_capacity(6 * radius);
+        for direction in Pointy::ALL.iter() {
+            for _ in 0..radius {
+                ring.push(hex);
+                hex = hex + direction.offset();
+            }
+        }
+        ring
+    }
+
+    /// The hexes at most `radius` steps away, ring by ring from this one.
+    #[allow(dead_code)]
+    pub fn spiral(self, radius: usize) -> Vec<Axial> {
+        (0..=radius).flat_map(|ring| self.ring(ring)).collect()
+    }
+}
+
+impl From<Cube> for Axial {
+    fn from(cube: Cube) -> Self {
+        Axial::new(cube.q, cube.r)
+    }
+}
+
+impl From<Axial> for Cube {
+    fn from(axial: Axial) -> Self {
+        Cube {
+            q: axial.q,
+            r: axial.r,
+            s: axial.s(),
+        }
+    }
+}
+
+impl fmt::Display for Axial {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.q, self.r)
+    }
+}
+
+impl Add for Axial {
+    type Output = Axial;
+
+    fn add(self, other: Axial) -> Axial {
+        Axial::new(self.q + other.q, self.r + other.r)
+    }
+}
+
+impl Sub for Axial {
+    type Output = Axial;
+
+    fn sub(self, other: Axial) -> Axial {
+        Axial::new(self.q - other.q, self.r - other.r)
+    }
+}
+
+impl Neg for Axial {
+    type Output = Axial;
+
+    fn neg(self) -> Axial {
+        Axial::new(-self.q, -self.r)
+    }
+}
+
+impl Mul<isize> for Axial {
+    type Output = Axial;
+
+    fn mul(self, factor: isize) -> Axial {
+        Axial::new(self.q * factor, self.r * factor)
+    }
+}
+
+/// Reads a path of directions written one after the other without anything
+/// in between, such as `nwwswee`.
+fn parse_path<D: Copy>(text: &str, parse: fn(&str) -> Option<D>) -> Option<Vec<D>> {
+    let mut path = Vec::new();
+    let mut rest = text;
+    while !rest.is_empty() {
+        // Two letter directions come first, so `ne` isn't read as `n` and `e`.
+        let (direction, length) = match rest.get(..2).and_then(parse) {
+            Some(direction) => (direction, 2),
+            None => (rest.get(..1).and_then(parse)?, 1),
+        };
+        path.push(direction);
+        rest = &rest[length..];
+    }
+    Some(path)
+}
+
+impl Pointy {
+    /// Every direction, counterclockwise from the east.
+    pub const ALL: [Pointy; 6] = [
+        Pointy::East,
+        Pointy::NorthEast,
+        Pointy::NorthWest,
+        Pointy::West,
+        Pointy::SouthWest,
+        Pointy::SouthEast,
+    ];
+
+    /// Parses `e`, `ne`, `nw`, `w`, `sw` or `se`.
+    pub fn parse(text: &str) -> Option<Pointy> {
+        let direction = match text {
+            "e" => Pointy::East,
+            "ne" => Pointy::NorthEast,
+            "nw" => Pointy::NorthWest,
+            "w" => Pointy::West,
+            "sw" => Pointy::SouthWest,
+            "se" => Pointy::SouthEast,
+            _ => return None,
+        };
+        Some(direction)
+    }
+
+    pub fn parse_path(text: &str) -> Option<Vec<Pointy>> {
+        parse_path(text, Pointy::parse)
+    }
+
+    /// The offset of a single step in this direction.
+    pub fn offset(self) -> Axial {
+        match self {
+            Pointy::East => Axial::new(1, 0),
+            Pointy::NorthEast => Axial::new(1, -1),
+            Pointy::NorthWest => Axial::new(0, -1),
+            Pointy::West => Axial::new(-1, 0),
+            Pointy::SouthWest => Axial::new(-1, 1),
+            Pointy::SouthEast => Axial::new(0, 1),
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl Flat {
+    /// Every direction, counterclockwise from the north.
+    pub const ALL: [Flat; 6] = [
+        Flat::North,
+        Flat::NorthWest,
+        Flat::SouthWest,
+        Flat::South,
+        Flat::SouthEast,
+        Flat::NorthEast,
+    ];
+
+    /// Parses `n`, `nw`, `sw`, `s`, `se` or `ne`.
+    pub fn parse(text: &str) -> Option<Flat> {
+        let direction = match text {
+            "n" => Flat::North,
+            "nw" => Flat::NorthWest,
+            "sw" => Flat::SouthWest,
+            "s" => Flat::South,
+            "se" => Flat::SouthEast,
+            "ne" => Flat::NorthEast,
+            _ => return None,
+        };
+        Some(direction)
+    }
+
+    pub fn parse_path(text: &str) -> Option<Vec<Flat>> {
+        parse_path(text, Flat::parse)
+    }
+
+    /// The offset of a single step in this direction.
+    pub fn offset(self) -> Axial {
+        match self {
+            Flat::North => Axial::new(0, -1),
+            Flat::NorthWest => Axial::new(-1, 0),
+            Flat::SouthWest => Axial::new(-1, 1),
+            Flat::South => Axial::new(0, 1),
+            Flat::SouthEast => Axial::new(1, 0),
+            Flat::NorthEast => Axial::new(1, -1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_paths() {
+        use Pointy::*;
+        assert_eq!(
+            Pointy::parse_path("nwwswee"),
+            Some(vec![NorthWest, West, SouthWest, East, East])
+        );
+        assert_eq!(Pointy::parse_path("nw x"), None);
+        assert_eq!(
+            Flat::parse_path("nnesw"),
+            Some(vec![Flat::North, Flat::NorthEast, Flat::SouthWest])
+        );
+    }
+
+    #[test]
+    fn walks_and_measures() {
+        // The example path of 2020 day 24 ends three hexes east.
+        let end = Pointy::parse_path("esenee")
+            .unwrap()
+            .iter()
+            .fold(Axial::ORIGIN, |hex, direction| hex + direction.offset());
+        assert_eq!(end, Axial::new(3, 0));
+        assert_eq!(Axial::ORIGIN.distance(end), 3);
+        assert_eq!(Axial::new(1, -3).distance(Axial::new(-2, 1)), 4);
+
+        // Each direction is three turns away from its opposite.
+        for (i, direction) in Pointy::ALL.iter().enumerate() {
+            assert_eq!(Pointy::ALL[(i + 3) % 6].offset(), -direction.offset());
+        }
+        for (i, direction) in Flat::ALL.iter().enumerate() {
+            assert_eq!(Flat::ALL[(i + 3) % 6].offset(), -direction.offset());
+            assert_eq!(Axial::ORIGIN.distance(direction.offset()), 1);
+        }
+
+        let cube = Cube::from(Axial::new(2, -5));
+        assert_eq!(cube.q + cube.r + cube.s, 0);
+        assert_eq!(Axial::from(cube), Axial::new(2, -5));
+    }
+
+    #[test]
+    fn builds_rings_and_spirals() {
+        let center = Axial::new(4, -1);
+        let neighbours: Vec<Axial> = center.neighbours().collect();
+        let mut ring = center.ring(1);
+        ring.sort();
+        let mut sorted = neighbours.clone();
+        sorted.sort();
+        assert_eq!(ring, sorted);
+
+        let ring = center.ring(3);
+        assert_eq!(ring.len(), 18);
+        assert!(ring.iter().all(|hex| center.distance(*hex) == 3));
+
+        let spiral = center.spiral(2);
+        assert_eq!(spiral.len(), 19);
+        assert_eq!(spiral[0], center);
+    }
+}
@@ -1 +1,7 @@
+pub mod automaton;
+pub mod direction;
+pub mod graph;
+pub mod grid;
+pub mod hex;
 pub mod point_2d;
+pub mod point_3d;
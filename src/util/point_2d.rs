@@ -1,11 +1,91 @@
-use std::ops::{Add, Neg, Sub};
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
 
-#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+/// A point or offset on a grid. Ordered by `x`, then `y`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct Point2D {
     pub x: isize,
     pub y: isize,
 }
 
+impl Point2D {
+    pub const ORIGIN: Point2D = Point2D { x: 0, y: 0 };
+
+    pub const fn new(x: isize, y: isize) -> Self {
+        Point2D { x, y }
+    }
+
+    /// The point at a column and row of a grid.
+    pub fn from_usize(x: usize, y: usize) -> Self {
+        Point2D::new(x as isize, y as isize)
+    }
+
+    /// The column and row of the point, unless a coordinate is negative.
+    pub fn to_usize(self) -> Option<(usize, usize)> {
+        if self.x < 0 || self.y < 0 {
+            return None;
+        }
+        Some((self.x as usize, self.y as usize))
+    }
+
+    pub fn manhattan_distance(self, other: Point2D) -> usize {
+        let difference = self - other;
+        difference.x.unsigned_abs() + difference.y.unsigned_abs()
+    }
+
+    /// The number of king moves between two points.
+    pub fn chebyshev_distance(self, other: Point2D) -> usize {
+        let difference = self - other;
+        difference.x.unsigned_abs().max(difference.y.unsigned_abs())
+    }
+
+    /// The smallest offset pointing the same way, found by dividing both
+    /// coordinates by their greatest common divisor. The origin stays put.
+    pub fn reduced(self) -> Point2D {
+        match gcd(self.x.unsigned_abs(), self.y.unsigned_abs()) {
+            0 => self,
+            divisor => self / divisor as isize,
+        }
+    }
+
+    /// Turns a quarter clockwise around the origin, with `y` going down as it
+    /// does on a grid, so up becomes right.
+    pub fn rotate_right(self) -> Point2D {
+        Point2D::new(-self.y, self.x)
+    }
+
+    /// Turns a quarter counterclockwise around the origin, so up becomes left.
+    pub fn rotate_left(self) -> Point2D {
+        Point2D::new(self.y, -self.x)
+    }
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+impl fmt::Display for Point2D {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl From<(isize, isize)> for Point2D {
+    fn from((x, y): (isize, isize)) -> Self {
+        Point2D { x, y }
+    }
+}
+
+impl From<Point2D> for (isize, isize) {
+    fn from(point: Point2D) -> Self {
+        (point.x, point.y)
+    }
+}
+
 impl Sub for Point2D {
     type Output = Point2D;
 
@@ -28,6 +108,18 @@ impl Add for Point2D {
     }
 }
 
+impl AddAssign for Point2D {
+    fn add_assign(&mut self, other: Point2D) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Point2D {
+    fn sub_assign(&mut self, other: Point2D) {
+        *self = *self - other;
+    }
+}
+
 impl Neg for Point2D {
     type Output = Point2D;
 
@@ -38,3 +130,65 @@ impl Neg for Point2D {
         }
     }
 }
+
+impl Mul<isize> for Point2D {
+    type Output = Point2D;
+
+    fn mul(self, factor: isize) -> Point2D {
+        Point2D {
+            x: self.x * factor,
+            y: self.y * factor,
+        }
+    }
+}
+
+impl Div<isize> for Point2D {
+    type Output = Point2D;
+
+    fn div(self, divisor: isize) -> Point2D {
+        Point2D {
+            x: self.x / divisor,
+            y: self.y / divisor,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn measures_distances() {
+        let a = Point2D::new(1, -2);
+        let b = Point2D::new(-3, 4);
+        assert_eq!(a.manhattan_distance(b), 10);
+        assert_eq!(a.chebyshev_distance(b), 6);
+        assert_eq!(a.manhattan_distance(a), 0);
+    }
+
+    #[test]
+    fn reduces_and_rotates() {
+        assert_eq!(Point2D::new(6, -4).reduced(), Point2D::new(3, -2));
+        assert_eq!(Point2D::new(0, 5).reduced(), Point2D::new(0, 1));
+        assert_eq!(Point2D::ORIGIN.reduced(), Point2D::ORIGIN);
+
+        let up = Point2D::new(0, -1);
+        assert_eq!(up.rotate_right(), Point2D::new(1, 0));
+        assert_eq!(up.rotate_left(), Point2D::new(-1, 0));
+        assert_eq!(
+            up.rotate_right().rotate_right().rotate_right(),
+            up.rotate_left()
+        );
+    }
+
+    #[test]
+    fn converts_and_formats() {
+        let point = Point2D::from((2, -7));
+        assert_eq!(<(isize, isize)>::from(point), (2, -7));
+        assert_eq!(point.to_usize(), None);
+        assert_eq!(Point2D::from_usize(3, 4).to_usize(), Some((3, 4)));
+        assert_eq!(point * 2 / 2, point);
+        assert_eq!(point.to_string(), "(2, -7)");
+        assert!(Point2D::new(1, 9) < Point2D::new(2, 0));
+    }
+}
@@ -0,0 +1,138 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
+/// A point or offset in space. Ordered by `x`, then `y`, then `z`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Point3D {
+    pub x: isize,
+    pub y: isize,
+    pub z: isize,
+}
+
+impl Point3D {
+    pub const ORIGIN: Point3D = Point3D { x: 0, y: 0, z: 0 };
+
+    pub const fn new(x: isize, y: isize, z: isize) -> Self {
+        Point3D { x, y, z }
+    }
+
+    pub fn manhattan_distance(self, other: Point3D) -> usize {
+        let difference = self - other;
+        difference.x.unsigned_abs() + difference.y.unsigned_abs() + difference.z.unsigned_abs()
+    }
+
+    /// The number of king moves between two points.
+    pub fn chebyshev_distance(self, other: Point3D) -> usize {
+        let difference = self - other;
+        difference
+            .x
+            .unsigned_abs()
+            .max(difference.y.unsigned_abs())
+            .max(difference.z.unsigned_abs())
+    }
+}
+
+impl fmt::Display for Point3D {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+impl From<(isize, isize, isize)> for Point3D {
+    fn from((x, y, z): (isize, isize, isize)) -> Self {
+        Point3D { x, y, z }
+    }
+}
+
+impl From<Point3D> for (isize, isize, isize) {
+    fn from(point: Point3D) -> Self {
+        (point.x, point.y, point.z)
+    }
+}
+
+impl Add for Point3D {
+    type Output = Point3D;
+
+    fn add(self, other: Point3D) -> Point3D {
+        Point3D {
+            x: self.x + other.x,
+            y: self.y + other.y,
+            z: self.z + other.z,
+        }
+    }
+}
+
+impl Sub for Point3D {
+    type Output = Point3D;
+
+    fn sub(self, other: Point3D) -> Point3D {
+        Point3D {
+            x: self.x - other.x,
+            y: self.y - other.y,
+            z: self.z - other.z,
+        }
+    }
+}
+
+impl AddAssign for Point3D {
+    fn add_assign(&mut self, other: Point3D) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Point3D {
+    fn sub_assign(&mut self, other: Point3D) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for Point3D {
+    type Output = Point3D;
+
+    fn neg(self) -> Point3D {
+        Point3D {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+        }
+    }
+}
+
+impl Mul<isize> for Point3D {
+    type Output = Point3D;
+
+    fn mul(self, factor: isize) -> Point3D {
+        Point3D {
+            x: self.x * factor,
+            y: self.y * factor,
+            z: self.z * factor,
+        }
+    }
+}
+
+impl Div<isize> for Point3D {
+    type Output = Point3D;
+
+    fn div(self, divisor: isize) -> Point3D {
+        Point3D {
+            x: self.x / divisor,
+            y: self.y / divisor,
+            z: self.z / divisor,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn measures_distances_and_formats() {
+        let a = Point3D::new(1, -2, 3);
+        let b = Point3D::from((-1, 2, 3));
+        assert_eq!(a.manhattan_distance(b), 6);
+        assert_eq!(a.chebyshev_distance(b), 4);
+        assert_eq!((a - b) * 3 / 3, Point3D::new(2, -4, 0));
+        assert_eq!(a.to_string(), "(1, -2, 3)");
+    }
+}
@@ -0,0 +1,391 @@
+use crate::answers::{parse_expected, KnownAnswers};
+use crate::cli::{Format, Part};
+use crate::input::InputSource;
+use crate::registry;
+use crate::report::{self, Record, Value};
+use crate::runner::{run_committed, run_from};
+use crate::solver::Answer;
+use colored::Colorize;
+use std::fs;
+use std::io;
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// The outcome of checking one part, or a whole day when it could not run.
+struct Check {
+    year: u32,
+    day: u32,
+    /// The name of the input file, when it isn't the committed one.
+    input: Option<String>,
+    part: Option<Part>,
+    answer: Option<Answer>,
+    status: Status,
+}
+
+enum Status {
+    Pass,
+    Fail(Answer),
+    Recorded,
+    Missing,
+    Error(String),
+}
+
+impl Check {
+    fn expected(&self) -> Option<&Answer> {
+        match &self.status {
+            Status::Pass => self.answer.as_ref(),
+            Status::Fail(expected) => Some(expected),
+            _ => None,
+        }
+    }
+
+    fn print(&self) {
+        let mut label = format!("{} day {:>2}", self.year, self.day);
+        if let Some(input) = &self.input {
+            label = format!("{} {}", label, input);
+        }
+        let label = match self.part {
+            Some(part) => format!("{} part {}", label, part.number()),
+            None => format!("{}       ", label),
+        };
+        let answer = self.answer.as_ref().map(one_line).unwrap_or_default();
+        match &self.status {
+            Status::Pass => println!("{} {}", label, "PASS".green()),
+            Status::Fail(expected) => println!(
+                "{} {} expected {}, got {}",
+                label,
+                "FAIL".red().bold(),
+                one_line(expected),
+                answer
+            ),
+            Status::Recorded => println!("{} {} {}", label, "RECORDED".cyan(), answer),
+            Status::Missing => println!("{} {} {}", label, "MISSING".yellow(), answer),
+            Status::Error(error) => println!("{} {} {}", label, "ERROR".red().bold(), error),
+        }
+    }
+
+    fn record(&self) -> Record {
+        let (status, error) = match &self.status {
+            Status::Pass => ("pass", None),
+            Status::Fail(_) => ("fail", None),
+            Status::Recorded => ("recorded", None),
+            Status::Missing => ("missing", None),
+            Status::Error(error) => ("error", Some(error.as_str())),
+        };
+        let mut record = vec![
+            ("year", Value::from(self.year)),
+            ("day", Value::from(self.day)),
+        ];
+        if let Some(input) = &self.input {
+            record.push(("input", Value::from(input.as_str())));
+        }
+        record.extend(vec![
+            ("part", Value::from(self.part.map(Part::number))),
+            ("status", Value::from(status)),
+            ("expected", Value::from(self.expected())),
+            ("actual", Value::from(self.answer.as_ref())),
+            ("error", Value::from(error)),
+        ]);
+        record
+    }
+}
+
+/// Collects the checks of `verify` and `run --inputs`, printing each one as it
+/// comes in when the output is text.
+struct Checks {
+    format: Format,
+    checks: Vec<Check>,
+}
+
+impl Checks {
+    fn new(format: Format) -> Self {
+        Checks {
+            format,
+            checks: Vec::new(),
+        }
+    }
+
+    fn push(&mut self, check: Check) {
+        if self.format == Format::Text {
+            check.print();
+        }
+        self.checks.push(check);
+    }
+
+    /// Prints the totals, or every check as a record, and fails when an answer
+    /// was wrong or could not be computed.
+    fn finish(self) -> Result<(), String> {
+        let count = |matches: fn(&Status) -> bool| {
+            self.checks
+                .iter()
+                .filter(|check| matches(&check.status))
+                .count()
+        };
+        let passed = count(|status| matches!(status, Status::Pass));
+        let failed = count(|status| matches!(status, Status::Fail(_) | Status::Error(_)));
+        let missing = count(|status| matches!(status, Status::Missing));
+        if self.format == Format::Text {
+            println!(
+                "{}, {}, {}",
+                format!("{} passed", passed).green(),
+                format!("{} failed", failed).red(),
+                format!("{} missing", missing).yellow()
+            );
+        } else {
+            let records: Vec<Record> = self.checks.iter().map(Check::record).collect();
+            report::print(self.format, &records);
+        }
+        if failed > 0 {
+            return Err(format!("{} answer(s) did not verify", failed));
+        }
+        Ok(())
+    }
+}
+
+pub fn verify(
+    year: Option<u32>,
+    days: Option<RangeInclusive<u32>>,
+    record: bool,
+    timeout: Option<Duration>,
+    format: Format,
+) -> Result<(), String> {
+    let years = match year {
+        Some(year) => vec![year],
+        None => registry::years(),
+    };
+
+    let mut checks = Checks::new(format);
+    for year in years {
+        let path = KnownAnswers::path(year);
+        let mut known =
+            KnownAnswers::load(year).map_err(|error| format!("{}: {}", path.display(), error))?;
+        let mut recorded = false;
+
+        for day in registry::resolve_days(year, days.clone())? {
+            let run = match run_committed(year, day, None, timeout) {
+                Ok(run) => run,
+                Err(error) => {
+                    checks.push(Check {
+                        year,
+                        day,
+                        input: None,
+                        part: None,
+                        answer: None,
+                        status: Status::Error(error),
+                    });
+                    continue;
+                }
+            };
+
+            for part_run in run.parts {
+                let part = part_run.part;
+                let (answer, status) = match part_run.answer {
+                    Ok(answer) => {
+                        let status = match known.get(day, part) {
+                            Some(expected) if *expected == answer => Status::Pass,
+                            Some(expected) => Status::Fail(expected.clone()),
+                            None if record => {
+                                known.insert(day, part, answer.clone());
+                                recorded = true;
+                                Status::Recorded
+                            }
+                            None => Status::Missing,
+                        };
+                        (Some(answer), status)
+                    }
+                    Err(error) => (None, Status::Error(error.to_string())),
+                };
+                checks.push(Check {
+                    year,
+                    day,
+                    input: None,
+                    part: Some(part),
+                    answer,
+                    status,
+                });
+            }
+        }
+
+        if recorded {
+            known
+                .save(year)
+                .map_err(|error| format!("{}: {}", path.display(), error))?;
+        }
+    }
+
+    checks.finish()
+}
+
+/// Runs a day on every file in a directory, checking each one against the
+/// answers in the `.answers` file next to it, such as `alice.answers` for
+/// `alice.txt`. Inputs without one are reported as missing.
+pub fn run_inputs(
+    year: u32,
+    day: u32,
+    directory: &Path,
+    part: Option<Part>,
+    timeout: Option<Duration>,
+    format: Format,
+) -> Result<(), String> {
+    let mut checks = Checks::new(format);
+    check_inputs(year, day, directory, part, timeout, &mut checks)?;
+    checks.finish()
+}
+
+fn check_inputs(
+    year: u32,
+    day: u32,
+    directory: &Path,
+    part: Option<Part>,
+    timeout: Option<Duration>,
+    checks: &mut Checks,
+) -> Result<(), String> {
+    let entries =
+        fs::read_dir(directory).map_err(|error| format!("{}: {}", directory.display(), error))?;
+    let mut inputs: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file() && path.extension().is_none_or(|ext| ext != "answers"))
+        .collect();
+    if inputs.is_empty() {
+        return Err(format!("No inputs found in {}", directory.display()));
+    }
+    inputs.sort();
+
+    for path in inputs {
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        let check = |part, answer, status| Check {
+            year,
+            day,
+            input: Some(name.to_string()),
+            part,
+            answer,
+            status,
+        };
+        let expected = match read_expected(&path) {
+            Ok(expected) => expected,
+            Err(error) => {
+                checks.push(check(None, None, Status::Error(error)));
+                continue;
+            }
+        };
+        let run = match run_from(year, day, part, &InputSource::File(path.clone()), timeout) {
+            Ok(run) => run,
+            Err(error) => {
+                checks.push(check(None, None, Status::Error(error)));
+                continue;
+            }
+        };
+
+        for part_run in run.parts {
+            let expected = expected
+                .iter()
+                .find(|(part, _)| *part == part_run.part)
+                .map(|(_, answer)| answer);
+            let (answer, status) = match part_run.answer {
+                Ok(answer) => {
+                    let status = match expected {
+                        Some(expected) if *expected == answer => Status::Pass,
+                        Some(expected) => Status::Fail(expected.clone()),
+                        None => Status::Missing,
+                    };
+                    (Some(answer), status)
+                }
+                Err(error) => (None, Status::Error(error.to_string())),
+            };
+            checks.push(check(Some(part_run.part), answer, status));
+        }
+    }
+    Ok(())
+}
+
+/// The answers expected for an input, which are none without an `.answers` file.
+fn read_expected(input: &Path) -> Result<Vec<(Part, Answer)>, String> {
+    let path = input.with_extension("answers");
+    let contents = match fs::read_to_string(&path) {
+        Ok(contents) => contents,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(error) => return Err(format!("{}: {}", path.display(), error)),
+    };
+    parse_expected(&contents).map_err(|error| format!("{}: {}", path.display(), error))
+}
+
+/// Answers shown inline, with art rows separated by slashes.
+fn one_line(answer: &Answer) -> String {
+    match answer {
+        Answer::Art(rows) => rows.join(" / "),
+        _ => answer.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "1721\n979\n366\n299\n675\n1456";
+
+    fn statuses(checks: &Checks, input: &str) -> Vec<(Option<u32>, String)> {
+        checks
+            .checks
+            .iter()
+            .filter(|check| check.input.as_deref() == Some(input))
+            .map(|check| {
+                let status = match &check.status {
+                    Status::Pass => "pass".to_string(),
+                    Status::Fail(expected) => format!("fail {}", expected),
+                    Status::Recorded => "recorded".to_string(),
+                    Status::Missing => "missing".to_string(),
+                    Status::Error(_) => "error".to_string(),
+                };
+                (check.part.map(Part::number), status)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn checks_inputs_against_their_answers() {
+        let directory = std::env::temp_dir().join(format!("aoc-inputs-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&directory);
+        fs::create_dir_all(&directory).unwrap();
+        let write = |name: &str, contents: &str| fs::write(directory.join(name), contents).unwrap();
+        write("pass.txt", EXAMPLE);
+        write("pass.answers", "1 514579\n2 241861950\n");
+        write("fail.txt", EXAMPLE);
+        write("fail.answers", "1 42\n");
+        write("missing.txt", EXAMPLE);
+        write("malformed.txt", EXAMPLE);
+        write("malformed.answers", "one 514579\n");
+        write("unparsable.txt", "not a number");
+
+        let mut checks = Checks::new(Format::Json);
+        check_inputs(2020, 1, &directory, None, None, &mut checks).unwrap();
+        let pass = |part| (Some(part), "pass".to_string());
+        let missing = |part| (Some(part), "missing".to_string());
+        assert_eq!(statuses(&checks, "pass.txt"), vec![pass(1), pass(2)]);
+        assert_eq!(
+            statuses(&checks, "fail.txt"),
+            vec![(Some(1), "fail 42".to_string()), missing(2)]
+        );
+        assert_eq!(
+            statuses(&checks, "missing.txt"),
+            vec![missing(1), missing(2)]
+        );
+        let error = vec![(None, "error".to_string())];
+        assert_eq!(statuses(&checks, "malformed.txt"), error);
+        assert_eq!(statuses(&checks, "unparsable.txt"), error);
+        assert!(checks.finish().is_err());
+
+        fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn reports_an_empty_directory() {
+        let directory = std::env::temp_dir().join(format!("aoc-no-inputs-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&directory);
+        fs::create_dir_all(&directory).unwrap();
+        let mut checks = Checks::new(Format::Json);
+        assert!(check_inputs(2020, 1, &directory, None, None, &mut checks).is_err());
+        fs::remove_dir_all(&directory).unwrap();
+    }
+}
@@ -0,0 +1,226 @@
+use crate::input::default_input_path;
+use crate::report;
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+type Snapshot = BTreeMap<PathBuf, SystemTime>;
+
+/// Answers or errors of a run by part number.
+type Answers = BTreeMap<String, String>;
+
+/// Polls the source, input and examples of a day, and reruns its tests and
+/// the solver through cargo whenever one of them changes. Never returns
+/// unless the files can't be listed.
+pub fn watch(year: u32, day: u32) -> Result<(), String> {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let mut snapshot = take_snapshot(&watched_files(manifest_dir, year, day)?);
+    let mut previous = None;
+    println!(
+        "Watching {} file(s) of {} day {}",
+        snapshot.len(),
+        year,
+        day
+    );
+
+    loop {
+        println!();
+        println!("===== {}: Day {} =====", year, day);
+        run_tests(manifest_dir, year, day);
+        let answers = run_solver(manifest_dir, year, day);
+        if let Some(answers) = &answers {
+            for line in compare(previous.as_ref(), answers) {
+                println!("  - {}", line);
+            }
+        }
+        previous = answers.or(previous);
+
+        let changed = loop {
+            thread::sleep(POLL_INTERVAL);
+            let current = take_snapshot(&watched_files(manifest_dir, year, day)?);
+            let changed = changed_files(&snapshot, &current);
+            if !changed.is_empty() {
+                snapshot = current;
+                break changed;
+            }
+        };
+        for path in changed {
+            let path = path.strip_prefix(manifest_dir).unwrap_or(&path);
+            println!("Changed {}", path.display());
+        }
+    }
+}
+
+/// The day's source and input, and every example input and `.answers` file.
+fn watched_files(manifest_dir: &Path, year: u32, day: u32) -> Result<Vec<PathBuf>, String> {
+    let year_dir = manifest_dir.join("src").join(year.to_string());
+    let mut files = vec![
+        year_dir.join(format!("day_{:02}.rs", day)),
+        default_input_path(year, day),
+    ];
+    let example_prefix = format!("day_{:02}_example", day);
+    let entries =
+        fs::read_dir(&year_dir).map_err(|error| format!("{}: {}", year_dir.display(), error))?;
+    for entry in entries.filter_map(|entry| entry.ok()) {
+        let name = entry.file_name().to_string_lossy().to_string();
+        if name.starts_with(&example_prefix) {
+            files.push(entry.path());
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+fn take_snapshot(files: &[PathBuf]) -> Snapshot {
+    files
+        .iter()
+        .filter_map(|path| {
+            let modified = fs::metadata(path).and_then(|metadata| metadata.modified());
+            Some((path.clone(), modified.ok()?))
+        })
+        .collect()
+}
+
+/// Files that were added, removed or modified between two snapshots.
+fn changed_files(before: &Snapshot, after: &Snapshot) -> Vec<PathBuf> {
+    let mut changed: Vec<PathBuf> = after
+        .iter()
+        .filter(|(path, modified)| before.get(*path) != Some(modified))
+        .map(|(path, _)| path.clone())
+        .collect();
+    changed.extend(
+        before
+            .keys()
+            .filter(|path| !after.contains_key(*path))
+            .cloned(),
+    );
+    changed
+}
+
+fn cargo(manifest_dir: &Path) -> Command {
+    let mut command = Command::new(env::var("CARGO").unwrap_or_else(|_| "cargo".to_string()));
+    command.current_dir(manifest_dir);
+    command
+}
+
+/// Runs the day's unit tests and example tests, only showing their output
+/// when they fail.
+fn run_tests(manifest_dir: &Path, year: u32, day: u32) {
+    let output = cargo(manifest_dir)
+        .args(["test", "--quiet", "--"])
+        .arg(format!("y_{}::day_{:02}::", year, day))
+        .arg(format!("y{}_day_{:02}_", year, day))
+        .output();
+    match output {
+        Ok(output) if output.status.success() => println!("  - Tests: passed"),
+        Ok(output) => {
+            println!("  - Tests: failed");
+            print!("{}", String::from_utf8_lossy(&output.stdout));
+            eprint!("{}", String::from_utf8_lossy(&output.stderr));
+        }
+        Err(error) => println!("  - Tests: could not run cargo: {}", error),
+    }
+}
+
+/// Builds and runs the solver, returning its answers unless it didn't build.
+/// Build and solver errors are shown as they happen.
+fn run_solver(manifest_dir: &Path, year: u32, day: u32) -> Option<Answers> {
+    let output = cargo(manifest_dir)
+        .args([
+            "run",
+            "--release",
+            "--quiet",
+            "--",
+            "run",
+            "--format",
+            "csv",
+        ])
+        .arg(year.to_string())
+        .arg(day.to_string())
+        .stderr(Stdio::inherit())
+        .output();
+    let output = match output {
+        Ok(output) => output,
+        Err(error) => {
+            println!("  - Solver: could not run cargo: {}", error);
+            return None;
+        }
+    };
+
+    let rows = report::parse_csv(&String::from_utf8_lossy(&output.stdout));
+    let header = rows.first()?;
+    let column = |name| header.iter().position(|column| column == name);
+    let (part, answer, error) = (column("part")?, column("answer")?, column("error")?);
+
+    let answers = rows[1..]
+        .iter()
+        .filter(|row| row.len() == header.len() && !row[part].is_empty())
+        .map(|row| {
+            let value = if row[error].is_empty() {
+                row[answer].replace('\n', " / ")
+            } else {
+                format!("error: {}", row[error])
+            };
+            (row[part].clone(), value)
+        })
+        .collect();
+    Some(answers)
+}
+
+/// Describes each answer, and how it changed since the previous run.
+fn compare(previous: Option<&Answers>, answers: &Answers) -> Vec<String> {
+    answers
+        .iter()
+        .map(|(part, answer)| {
+            let change = match previous.and_then(|previous| previous.get(part)) {
+                Some(before) if before == answer => " (unchanged)".to_string(),
+                Some(before) => format!(" (was {})", before),
+                None => String::new(),
+            };
+            format!("Part {}: {}{}", part, answer, change)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_changed_files() {
+        let time = SystemTime::UNIX_EPOCH;
+        let later = time + Duration::from_secs(1);
+        let before: Snapshot = vec![("a".into(), time), ("b".into(), time)]
+            .into_iter()
+            .collect();
+        let after: Snapshot = vec![("a".into(), later), ("c".into(), time)]
+            .into_iter()
+            .collect();
+        let changed: Vec<PathBuf> = vec!["a".into(), "c".into(), "b".into()];
+        assert_eq!(changed_files(&before, &after), changed);
+        assert!(changed_files(&after, &after).is_empty());
+    }
+
+    #[test]
+    fn compares_answers_with_the_previous_run() {
+        let answers = |values: &[(&str, &str)]| -> Answers {
+            values
+                .iter()
+                .map(|(part, answer)| (part.to_string(), answer.to_string()))
+                .collect()
+        };
+        let previous = answers(&[("1", "42"), ("2", "7")]);
+        let current = answers(&[("1", "42"), ("2", "8")]);
+        assert_eq!(
+            compare(Some(&previous), &current),
+            vec!["Part 1: 42 (unchanged)", "Part 2: 8 (was 7)"]
+        );
+        assert_eq!(compare(None, &current), vec!["Part 1: 42", "Part 2: 8"]);
+    }
+}